@@ -24,8 +24,16 @@ pub enum Error {
     InsufficientReceivedAmount = 104,
     Slippage = 105,
     InvalidFirstDeposit = 106,
+    Paused = 107,
+    RateDecreased = 108,
+    LpLocked = 109,
+    IntentExpired = 110,
 
     // Factory
     PairExist = 200,
     IdenticalAddresses = 201,
+    PermissionlessDisabled = 202,
+    TokenNotListed = 203,
+    TokenBlocked = 204,
+    TokenDecimalsMismatch = 205,
 }
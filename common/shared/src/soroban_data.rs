@@ -97,6 +97,14 @@ pub trait SorobanData:
         }
     }
 
+    fn remove_by_key<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+        match Self::STORAGE_TYPE {
+            StorageType::Instance => env.storage().instance().remove(key),
+            StorageType::Temporary => env.storage().temporary().remove(key),
+            StorageType::Persistent => env.storage().persistent().remove(key),
+        }
+    }
+
     #[inline]
     fn has_by_key<K: IntoVal<Env, Val>>(env: &Env, key: K) -> bool {
         Self::get_by_key(env, &key).is_ok()
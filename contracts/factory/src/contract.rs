@@ -1,7 +1,25 @@
 use shared::{utils::extend_ttl_instance, Error};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, Vec};
 
-use crate::methods::public::{create_pair, get_pool, get_pools, initialize};
+use crate::{
+    methods::public::{
+        block_token, collect_protocol_fees, create_default_pair, create_metapool, create_pair,
+        create_pair_with_liquidity, get_pair_config, get_pool, get_pool_by_index, get_pool_by_tier,
+        get_pools, get_pools_count, get_pools_for_pair, get_pools_page, get_protocol_fee,
+        get_token_info, initialize, list_token, predict_pool_address, ramp_pools_a, remove_pair,
+        replace_pair, set_default_pool_params, set_permissionless, set_pool_params_ranges,
+        set_pool_precision, set_pools_admin_fee_share, set_pools_fee_share, set_pools_paused,
+        set_protocol_fee, set_rate_max_age, set_token_rate_provider, stop_pools_a_ramp,
+        sync_pools_protocol_fee,
+    },
+    storage::{
+        factory_info::FactoryInfo,
+        pair_config::{PairConfig, PoolParams},
+        pool_record::PoolRecord,
+        protocol_fee::ProtocolFee,
+        token_info::TokenInfo,
+    },
+};
 
 #[contract]
 pub struct FactoryContract;
@@ -22,6 +40,7 @@ impl FactoryContract {
         token_b: Address,
         fee_share_bp: u128,
         admin_fee_share_bp: u128,
+        tier: u32,
     ) -> Result<Address, Error> {
         extend_ttl_instance(&env);
 
@@ -34,16 +53,343 @@ impl FactoryContract {
             token_b,
             fee_share_bp,
             admin_fee_share_bp,
+            tier,
         )
     }
 
+    /// Creates a pair and makes its first deposit from `deployer`,
+    /// the pool locks its minimum liquidity out of it
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_pair_with_liquidity(
+        env: Env,
+        deployer: Address,
+        pool_admin: Address,
+        a: u128,
+        token_a: Address,
+        token_b: Address,
+        fee_share_bp: u128,
+        admin_fee_share_bp: u128,
+        tier: u32,
+        amounts: (u128, u128),
+        min_lp_amount: u128,
+    ) -> Result<Address, Error> {
+        extend_ttl_instance(&env);
+
+        create_pair_with_liquidity(
+            env,
+            deployer,
+            pool_admin,
+            a,
+            token_a,
+            token_b,
+            fee_share_bp,
+            admin_fee_share_bp,
+            tier,
+            amounts,
+            min_lp_amount,
+        )
+    }
+
+    /// Creates a metapool of `token` against the LP of an existing pool,
+    /// the base pool is looked up by its pair and tier
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_metapool(
+        env: Env,
+        deployer: Address,
+        pool_admin: Address,
+        a: u128,
+        token: Address,
+        base_token_a: Address,
+        base_token_b: Address,
+        base_tier: u32,
+        fee_share_bp: u128,
+        admin_fee_share_bp: u128,
+        tier: u32,
+    ) -> Result<Address, Error> {
+        extend_ttl_instance(&env);
+
+        create_metapool(
+            env,
+            deployer,
+            pool_admin,
+            a,
+            token,
+            base_token_a,
+            base_token_b,
+            base_tier,
+            fee_share_bp,
+            admin_fee_share_bp,
+            tier,
+        )
+    }
+
+    /// Creates a default tier pair with the default pool params,
+    /// available to anyone in permissionless mode
+    pub fn create_default_pair(
+        env: Env,
+        deployer: Address,
+        token_a: Address,
+        token_b: Address,
+    ) -> Result<Address, Error> {
+        extend_ttl_instance(&env);
+
+        create_default_pair(env, deployer, token_a, token_b)
+    }
+
+    /// Claims the protocol fees accrued by `pools` to the `fee_to` address,
+    /// returns the claimed amounts per pool, `None` for a failed claim
+    #[allow(clippy::type_complexity)]
+    pub fn collect_protocol_fees(
+        env: Env,
+        pools: Vec<Address>,
+    ) -> Result<Vec<(Address, Option<(u128, u128)>)>, Error> {
+        extend_ttl_instance(&env);
+
+        collect_protocol_fees(env, pools)
+    }
+
+    // -------- Admin --------
+
+    pub fn set_permissionless(env: Env, permissionless: bool) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_permissionless(env, permissionless)
+    }
+
+    pub fn set_default_pool_params(env: Env, params: PoolParams) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_default_pool_params(env, params)
+    }
+
+    pub fn set_pool_params_ranges(
+        env: Env,
+        a_range: (u128, u128),
+        fee_share_bp_range: (u128, u128),
+        admin_fee_share_bp_range: (u128, u128),
+    ) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_pool_params_ranges(env, a_range, fee_share_bp_range, admin_fee_share_bp_range)
+    }
+
+    /// System precision and balance cap of the pools created from now on
+    pub fn set_pool_precision(
+        env: Env,
+        system_precision: u32,
+        max_token_balance: u128,
+    ) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_pool_precision(env, system_precision, max_token_balance)
+    }
+
+    pub fn set_rate_max_age(env: Env, rate_max_age: u64) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_rate_max_age(env, rate_max_age)
+    }
+
+    /// New pools take the share at creation, existing ones with `sync_pools_protocol_fee`
+    pub fn set_protocol_fee(env: Env, share_bp: u128, fee_to: Address) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_protocol_fee(env, share_bp, fee_to)
+    }
+
+    /// Allows pairs with the token, `decimals` must match the token's `decimals()`
+    pub fn list_token(env: Env, token: Address, decimals: u32, stable: bool) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        list_token(env, token, decimals, stable)
+    }
+
+    /// Rate provider of a yield-bearing or wrapped token, used by pools created afterwards
+    pub fn set_token_rate_provider(
+        env: Env,
+        token: Address,
+        rate_provider: Option<Address>,
+    ) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_token_rate_provider(env, token, rate_provider)
+    }
+
+    /// Blocks the token for good, its pools and the metapools on them are removed
+    /// from the registry and paused
+    pub fn block_token(env: Env, token: Address) -> Result<Vec<Address>, Error> {
+        extend_ttl_instance(&env);
+
+        block_token(env, token)
+    }
+
+    /// Removes the pool from the registry, `withdraw_only` pauses it.
+    /// The pool address of the tier stays taken, use `replace_pair` to register a new pool.
+    /// Returns the removed pool
+    pub fn remove_pair(
+        env: Env,
+        token_a: Address,
+        token_b: Address,
+        tier: u32,
+        withdraw_only: bool,
+    ) -> Result<Address, Error> {
+        extend_ttl_instance(&env);
+
+        remove_pair(env, token_a, token_b, tier, withdraw_only)
+    }
+
+    /// Registers `pool` instead of the current pool of the tier, `withdraw_only` pauses
+    /// the replaced pool. The pool must be created by the factory for the pair and
+    /// not be registered. Returns the replaced pool
+    pub fn replace_pair(
+        env: Env,
+        token_a: Address,
+        token_b: Address,
+        tier: u32,
+        pool: Address,
+        withdraw_only: bool,
+    ) -> Result<Address, Error> {
+        extend_ttl_instance(&env);
+
+        replace_pair(env, token_a, token_b, tier, pool, withdraw_only)
+    }
+
+    pub fn set_pools_fee_share(
+        env: Env,
+        pools: Vec<Address>,
+        fee_share_bp: u128,
+    ) -> Result<Vec<(Address, bool)>, Error> {
+        extend_ttl_instance(&env);
+
+        set_pools_fee_share(env, pools, fee_share_bp)
+    }
+
+    pub fn set_pools_admin_fee_share(
+        env: Env,
+        pools: Vec<Address>,
+        admin_fee_share_bp: u128,
+    ) -> Result<Vec<(Address, bool)>, Error> {
+        extend_ttl_instance(&env);
+
+        set_pools_admin_fee_share(env, pools, admin_fee_share_bp)
+    }
+
+    /// Moves A of `pools` linearly to `future_a` until `future_time`
+    pub fn ramp_pools_a(
+        env: Env,
+        pools: Vec<Address>,
+        future_a: u128,
+        future_time: u64,
+    ) -> Result<Vec<(Address, bool)>, Error> {
+        extend_ttl_instance(&env);
+
+        ramp_pools_a(env, pools, future_a, future_time)
+    }
+
+    /// Stops the A ramps of `pools` at their current A
+    pub fn stop_pools_a_ramp(env: Env, pools: Vec<Address>) -> Result<Vec<(Address, bool)>, Error> {
+        extend_ttl_instance(&env);
+
+        stop_pools_a_ramp(env, pools)
+    }
+
+    /// Pushes the protocol fee share to the pools
+    pub fn sync_pools_protocol_fee(
+        env: Env,
+        pools: Vec<Address>,
+    ) -> Result<Vec<(Address, bool)>, Error> {
+        extend_ttl_instance(&env);
+
+        sync_pools_protocol_fee(env, pools)
+    }
+
+    /// Disables swaps and deposits of `pools`, withdrawals stay available
+    pub fn pause_pools(env: Env, pools: Vec<Address>) -> Result<Vec<(Address, bool)>, Error> {
+        extend_ttl_instance(&env);
+
+        set_pools_paused(env, pools, true)
+    }
+
+    pub fn unpause_pools(env: Env, pools: Vec<Address>) -> Result<Vec<(Address, bool)>, Error> {
+        extend_ttl_instance(&env);
+
+        set_pools_paused(env, pools, false)
+    }
+
+    // -------- View --------
+
+    /// Pool of the default tier
     pub fn pool(env: Env, token_a: Address, token_b: Address) -> Result<Address, Error> {
         get_pool(env, &token_a, &token_b)
     }
 
-    pub fn pools(env: Env) -> Result<Map<Address, (Address, Address)>, Error> {
-        extend_ttl_instance(&env);
+    pub fn pool_by_tier(
+        env: Env,
+        token_a: Address,
+        token_b: Address,
+        tier: u32,
+    ) -> Result<Address, Error> {
+        get_pool_by_tier(env, &token_a, &token_b, tier)
+    }
+
+    pub fn pools_for_pair(
+        env: Env,
+        token_a: Address,
+        token_b: Address,
+    ) -> Result<Vec<PoolRecord>, Error> {
+        get_pools_for_pair(env, &token_a, &token_b)
+    }
+
+    pub fn pools_count(env: Env) -> Result<u32, Error> {
+        get_pools_count(env)
+    }
+
+    /// Pools by index, `limit` is capped at 50. A removed pool's index is taken
+    /// by the last pool, so the order is the creation order only until a removal
+    pub fn pools_page(env: Env, offset: u32, limit: u32) -> Result<Vec<PoolRecord>, Error> {
+        get_pools_page(env, offset, limit)
+    }
 
+    pub fn pool_by_index(env: Env, index: u32) -> Result<PoolRecord, Error> {
+        get_pool_by_index(env, index)
+    }
+
+    /// Deprecated, use `pools_page`. Every pool mapped to its tokens, the pools of all tiers
+    /// are included. Reads the whole index, so it fails once the registry outgrows
+    /// the read limits of a single call
+    pub fn pools(env: Env) -> Result<Map<Address, (Address, Address)>, Error> {
         get_pools(env)
     }
+
+    /// Address the default tier pool of the pair gets when created by `deployer`
+    pub fn predict_pool_address(
+        env: Env,
+        deployer: Address,
+        token_a: Address,
+        token_b: Address,
+    ) -> Result<Address, Error> {
+        predict_pool_address(env, deployer, token_a, token_b, FactoryInfo::DEFAULT_TIER)
+    }
+
+    pub fn predict_pool_address_by_tier(
+        env: Env,
+        deployer: Address,
+        token_a: Address,
+        token_b: Address,
+        tier: u32,
+    ) -> Result<Address, Error> {
+        predict_pool_address(env, deployer, token_a, token_b, tier)
+    }
+
+    pub fn pair_config(env: Env) -> Result<PairConfig, Error> {
+        get_pair_config(env)
+    }
+
+    pub fn protocol_fee(env: Env) -> Result<ProtocolFee, Error> {
+        get_protocol_fee(env)
+    }
+
+    pub fn token_info(env: Env, token: Address) -> Result<TokenInfo, Error> {
+        get_token_info(env, token)
+    }
 }
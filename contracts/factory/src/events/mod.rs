@@ -9,3 +9,22 @@ pub struct PairCreated {
     pub token1: Address,
     pub pool: Address,
 }
+
+#[derive(Event)]
+#[contracttype]
+pub struct PairRemoved {
+    pub token0: Address,
+    pub token1: Address,
+    pub tier: u32,
+    pub pool: Address,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct PairReplaced {
+    pub token0: Address,
+    pub token1: Address,
+    pub tier: u32,
+    pub old_pool: Address,
+    pub new_pool: Address,
+}
@@ -0,0 +1,138 @@
+use shared::{require, soroban_data::SimpleSorobanData, Error};
+use soroban_sdk::{Address, Env, Vec};
+use storage::Admin;
+
+use super::manage_pools::invoke_pools;
+use crate::storage::{
+    factory_info::FactoryInfo,
+    pair_config::{PairConfig, PoolParams},
+    pool_record::PoolRecord,
+    protocol_fee::ProtocolFee,
+    token_info::{TokenInfo, TokenPools, TokenRateProvider},
+};
+
+pub fn set_permissionless(env: Env, permissionless: bool) -> Result<(), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    PairConfig::update(&env, |pair_config| {
+        pair_config.permissionless = permissionless;
+        Ok(())
+    })
+}
+
+pub fn set_default_pool_params(env: Env, params: PoolParams) -> Result<(), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    PairConfig::update(&env, |pair_config| pair_config.set_default_params(params))
+}
+
+pub fn set_pool_params_ranges(
+    env: Env,
+    a_range: (u128, u128),
+    fee_share_bp_range: (u128, u128),
+    admin_fee_share_bp_range: (u128, u128),
+) -> Result<(), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    PairConfig::update(&env, |pair_config| {
+        pair_config.set_ranges(a_range, fee_share_bp_range, admin_fee_share_bp_range)
+    })
+}
+
+pub fn set_pool_precision(
+    env: Env,
+    system_precision: u32,
+    max_token_balance: u128,
+) -> Result<(), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    require!(max_token_balance > 0, Error::InvalidArg);
+
+    PairConfig::update(&env, |pair_config| {
+        pair_config.system_precision = system_precision;
+        pair_config.max_token_balance = max_token_balance;
+        Ok(())
+    })
+}
+
+pub fn set_protocol_fee(env: Env, share_bp: u128, fee_to: Address) -> Result<(), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    require!(share_bp <= ProtocolFee::MAX_SHARE_BP, Error::InvalidArg);
+
+    ProtocolFee { share_bp, fee_to }.save(&env);
+
+    Ok(())
+}
+
+pub fn list_token(env: Env, token: Address, decimals: u32, stable: bool) -> Result<(), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    if let Ok(token_info) = TokenInfo::get(&env, &token) {
+        require!(!token_info.blocked, Error::TokenBlocked);
+    }
+
+    TokenInfo {
+        decimals,
+        stable,
+        blocked: false,
+    }
+    .save(&env, &token);
+
+    TokenInfo::require_allowed(&env, &token)
+}
+
+pub fn set_token_rate_provider(
+    env: Env,
+    token: Address,
+    rate_provider: Option<Address>,
+) -> Result<(), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    TokenInfo::get(&env, &token)?;
+    TokenRateProvider::set(&env, &token, rate_provider);
+
+    Ok(())
+}
+
+pub fn set_rate_max_age(env: Env, rate_max_age: u64) -> Result<(), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    PairConfig::update(&env, |pair_config| {
+        pair_config.rate_max_age = rate_max_age;
+        Ok(())
+    })
+}
+
+/// Blocks the token, deregisters and pauses its pools, returns the deregistered pools.
+/// The metapools on a deregistered pool hold its LP, so they are deregistered as well
+pub fn block_token(env: Env, token: Address) -> Result<Vec<Address>, Error> {
+    Admin::require_exist_auth(&env)?;
+
+    let mut token_info = TokenInfo::get(&env, &token)?;
+    token_info.blocked = true;
+    token_info.save(&env, &token);
+
+    let mut factory_info = FactoryInfo::get(&env)?;
+    let mut pools = Vec::new(&env);
+    // The blocked token, then the LP of every deregistered pool
+    let mut held_tokens = Vec::from_array(&env, [token]);
+
+    while let Some(held_token) = held_tokens.pop_front() {
+        for (token_a, token_b, tier) in TokenPools::get(&env, &held_token).0.iter() {
+            // A metapool of the token on its own base pool is listed under both
+            if !PoolRecord::has_pair(&env, &token_a, &token_b, tier) {
+                continue;
+            }
+
+            let record = PoolRecord::remove(&env, &mut factory_info, &token_a, &token_b, tier)?;
+            held_tokens.push_back(record.pool.clone());
+            pools.push_back(record.pool);
+        }
+    }
+
+    factory_info.save(&env);
+    invoke_pools(&env, pools.clone(), "pause", Vec::new(&env));
+
+    Ok(pools)
+}
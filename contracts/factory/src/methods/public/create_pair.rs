@@ -2,7 +2,13 @@ use shared::{require, soroban_data::SimpleSorobanData, Error};
 use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
 use storage::Admin;
 
-use crate::storage::factory_info::FactoryInfo;
+use super::protocol_fee::init_pool_protocol_fee;
+use crate::storage::{
+    factory_info::FactoryInfo,
+    pair_config::{PairConfig, PoolParams},
+    pool_record::{DeployedPool, PoolRecord},
+    token_info::{TokenInfo, TokenRateProvider},
+};
 
 mod pool {}
 
@@ -16,6 +22,7 @@ pub fn create_pair(
     token_b: Address,
     fee_share_bp: u128,
     admin_fee_share_bp: u128,
+    tier: u32,
 ) -> Result<Address, Error> {
     if deployer != env.current_contract_address() {
         deployer.require_auth();
@@ -23,37 +30,256 @@ pub fn create_pair(
 
     Admin::require_exist_auth(&env)?;
 
+    let params = PoolParams {
+        a,
+        fee_share_bp,
+        admin_fee_share_bp,
+    };
+    PairConfig::get(&env)?.validate_params(&params)?;
+
+    deploy_pair(&env, deployer, pool_admin, token_a, token_b, tier, params)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_pair_with_liquidity(
+    env: Env,
+    deployer: Address,
+    pool_admin: Address,
+    a: u128,
+    token_a: Address,
+    token_b: Address,
+    fee_share_bp: u128,
+    admin_fee_share_bp: u128,
+    tier: u32,
+    amounts: (u128, u128),
+    min_lp_amount: u128,
+) -> Result<Address, Error> {
+    let pool = create_pair(
+        env.clone(),
+        deployer.clone(),
+        pool_admin,
+        a,
+        token_a.clone(),
+        token_b.clone(),
+        fee_share_bp,
+        admin_fee_share_bp,
+        tier,
+    )?;
+
+    // Pool tokens are sorted
+    let amounts = if token_a < token_b {
+        amounts
+    } else {
+        (amounts.1, amounts.0)
+    };
+
+    // The first deposit locks the pool's minimum liquidity
+    env.invoke_contract::<u128>(
+        &pool,
+        &Symbol::new(&env, "deposit"),
+        vec![
+            &env,
+            deployer.into_val(&env),
+            amounts.into_val(&env),
+            min_lp_amount.into_val(&env),
+        ],
+    );
+
+    Ok(pool)
+}
+
+/// Metapool of `token` against the LP of the base pool of (`base_token_a`, `base_token_b`, `base_tier`).
+/// Recorded as the pair of `token` and the base pool address
+#[allow(clippy::too_many_arguments)]
+pub fn create_metapool(
+    env: Env,
+    deployer: Address,
+    pool_admin: Address,
+    a: u128,
+    token: Address,
+    base_token_a: Address,
+    base_token_b: Address,
+    base_tier: u32,
+    fee_share_bp: u128,
+    admin_fee_share_bp: u128,
+    tier: u32,
+) -> Result<Address, Error> {
+    if deployer != env.current_contract_address() {
+        deployer.require_auth();
+    }
+
+    Admin::require_exist_auth(&env)?;
+
+    let params = PoolParams {
+        a,
+        fee_share_bp,
+        admin_fee_share_bp,
+    };
+    let pair_config = PairConfig::get(&env)?;
+    pair_config.validate_params(&params)?;
+
+    let base_pool = PoolRecord::get_by_pair(&env, &base_token_a, &base_token_b, base_tier)?.pool;
+    TokenInfo::require_allowed(&env, &token)?;
+
     let mut factory_info = FactoryInfo::get(&env)?;
+    let deployed_pool = deploy_pool(
+        &env,
+        &mut factory_info,
+        deployer,
+        token.clone(),
+        base_pool.clone(),
+        tier,
+        params.clone(),
+    )?;
+
+    env.invoke_contract::<()>(
+        &deployed_pool,
+        &Symbol::new(&env, "initialize_metapool"),
+        vec![
+            &env,
+            pool_admin.into_val(&env),
+            params.a.into_val(&env),
+            token.into_val(&env),
+            base_pool.into_val(&env),
+            params.fee_share_bp.into_val(&env),
+            params.admin_fee_share_bp.into_val(&env),
+            env.current_contract_address().into_val(&env),
+            pair_config.system_precision.into_val(&env),
+            pair_config.max_token_balance.into_val(&env),
+        ],
+    );
+    init_pool_protocol_fee(&env, &deployed_pool)?;
+
+    factory_info.save(&env);
+
+    Ok(deployed_pool)
+}
+
+pub fn create_default_pair(
+    env: Env,
+    deployer: Address,
+    token_a: Address,
+    token_b: Address,
+) -> Result<Address, Error> {
+    if deployer != env.current_contract_address() {
+        deployer.require_auth();
+    }
+
+    let pair_config = PairConfig::get(&env)?;
+    require!(pair_config.permissionless, Error::PermissionlessDisabled);
+
+    let pool_admin = Admin::get(&env)?;
+
+    deploy_pair(
+        &env,
+        deployer,
+        pool_admin.0,
+        token_a,
+        token_b,
+        FactoryInfo::DEFAULT_TIER,
+        pair_config.default_params,
+    )
+}
+
+fn deploy_pair(
+    env: &Env,
+    deployer: Address,
+    pool_admin: Address,
+    token_a: Address,
+    token_b: Address,
+    tier: u32,
+    params: PoolParams,
+) -> Result<Address, Error> {
+    let mut factory_info = FactoryInfo::get(env)?;
+    let pair_config = PairConfig::get(env)?;
 
     require!(token_a != token_b, Error::IdenticalAddresses);
+    TokenInfo::require_allowed(env, &token_a)?;
+    TokenInfo::require_allowed(env, &token_b)?;
+
+    let (token_a, token_b) = FactoryInfo::sort_tokens(token_a, token_b);
+
+    let args = vec![
+        env,
+        *pool_admin.as_val(),
+        params.a.into_val(env),
+        *token_a.as_val(),
+        *token_b.as_val(),
+        params.fee_share_bp.into_val(env),
+        params.admin_fee_share_bp.into_val(env),
+        *env.current_contract_address().as_val(),
+        pair_config.system_precision.into_val(env),
+        pair_config.max_token_balance.into_val(env),
+    ];
+
+    let rate_providers = (
+        TokenRateProvider::get(env, &token_a),
+        TokenRateProvider::get(env, &token_b),
+    );
+
+    let deployed_pool = deploy_pool(
+        env,
+        &mut factory_info,
+        deployer,
+        token_a,
+        token_b,
+        tier,
+        params,
+    )?;
+
+    env.invoke_contract::<()>(&deployed_pool, &Symbol::new(env, "initialize"), args);
+    init_pool_protocol_fee(env, &deployed_pool)?;
+
+    if rate_providers != (None, None) {
+        env.invoke_contract::<()>(
+            &deployed_pool,
+            &Symbol::new(env, "set_rate_providers"),
+            vec![
+                env,
+                rate_providers.into_val(env),
+                pair_config.rate_max_age.into_val(env),
+            ],
+        );
+    }
+
+    factory_info.save(env);
+
+    Ok(deployed_pool)
+}
+
+/// Deploys the pool of the pair and records it, the pool is left to be initialized
+fn deploy_pool(
+    env: &Env,
+    factory_info: &mut FactoryInfo,
+    deployer: Address,
+    token_a: Address,
+    token_b: Address,
+    tier: u32,
+    params: PoolParams,
+) -> Result<Address, Error> {
     require!(
-        factory_info.get_pool(&token_a, &token_b).is_err(),
+        !PoolRecord::has_pair(env, &token_a, &token_b, tier),
         Error::PairExist
     );
 
     let (token_a, token_b) = FactoryInfo::sort_tokens(token_a, token_b);
-    let bytes = FactoryInfo::merge_addresses(&token_a, &token_b)?;
-    let salt = env.crypto().keccak256(&bytes.into());
+    let salt = FactoryInfo::pool_salt(&token_a, &token_b, tier)?;
 
     let deployed_pool = env
         .deployer()
         .with_address(deployer, salt)
         .deploy(factory_info.wasm_hash.clone());
 
-    factory_info.add_pair((token_a.clone(), token_b.clone()), &deployed_pool);
+    DeployedPool(token_a.clone(), token_b.clone()).save(env, &deployed_pool);
 
-    let args = vec![
-        &env,
-        *pool_admin.as_val(),
-        a.into_val(&env),
-        *token_a.as_val(),
-        *token_b.as_val(),
-        fee_share_bp.into_val(&env),
-        admin_fee_share_bp.into_val(&env),
-    ];
-    env.invoke_contract::<()>(&deployed_pool, &Symbol::new(&env, "initialize"), args);
-
-    factory_info.save(&env);
+    PoolRecord {
+        pool: deployed_pool.clone(),
+        token_a,
+        token_b,
+        tier,
+        params,
+    }
+    .add(env, factory_info);
 
     Ok(deployed_pool)
 }
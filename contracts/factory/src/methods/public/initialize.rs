@@ -2,12 +2,20 @@ use shared::{require, soroban_data::SimpleSorobanData, Error};
 use soroban_sdk::{Address, BytesN, Env};
 use storage::Admin;
 
-use crate::storage::factory_info::FactoryInfo;
+use crate::storage::{
+    factory_info::FactoryInfo, pair_config::PairConfig, protocol_fee::ProtocolFee,
+};
 
 pub fn initialize(env: Env, wasm_hash: BytesN<32>, admin: Address) -> Result<(), Error> {
     require!(!FactoryInfo::has(&env), Error::Initialized);
 
     FactoryInfo::new(wasm_hash).save(&env);
+    PairConfig::default().save(&env);
+    ProtocolFee {
+        share_bp: 0,
+        fee_to: admin.clone(),
+    }
+    .save(&env);
     Admin(admin).save(&env);
 
     Ok(())
@@ -0,0 +1,90 @@
+use shared::{soroban_data::SimpleSorobanData, Error};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol, Val, Vec};
+use storage::Admin;
+
+use crate::storage::pair_config::PairConfig;
+
+/// (pool, success) for every pool of the batch
+pub type PoolsResult = Vec<(Address, bool)>;
+
+pub fn set_pools_fee_share(
+    env: Env,
+    pools: Vec<Address>,
+    fee_share_bp: u128,
+) -> Result<PoolsResult, Error> {
+    Admin::require_exist_auth(&env)?;
+    PairConfig::get(&env)?.validate_fee_share_bp(fee_share_bp)?;
+
+    Ok(invoke_pools(
+        &env,
+        pools,
+        "set_fee_share_by_factory",
+        vec![&env, fee_share_bp.into_val(&env)],
+    ))
+}
+
+pub fn set_pools_admin_fee_share(
+    env: Env,
+    pools: Vec<Address>,
+    admin_fee_share_bp: u128,
+) -> Result<PoolsResult, Error> {
+    Admin::require_exist_auth(&env)?;
+    PairConfig::get(&env)?.validate_admin_fee_share_bp(admin_fee_share_bp)?;
+
+    Ok(invoke_pools(
+        &env,
+        pools,
+        "set_admin_fee_share_by_factory",
+        vec![&env, admin_fee_share_bp.into_val(&env)],
+    ))
+}
+
+pub fn set_pools_paused(env: Env, pools: Vec<Address>, paused: bool) -> Result<PoolsResult, Error> {
+    Admin::require_exist_auth(&env)?;
+
+    let func = if paused { "pause" } else { "unpause" };
+
+    Ok(invoke_pools(&env, pools, func, Vec::new(&env)))
+}
+
+/// Ramps A of `pools` to `future_a` until `future_time`, the pools bound the ramp speed
+pub fn ramp_pools_a(
+    env: Env,
+    pools: Vec<Address>,
+    future_a: u128,
+    future_time: u64,
+) -> Result<PoolsResult, Error> {
+    Admin::require_exist_auth(&env)?;
+    PairConfig::get(&env)?.validate_a(future_a)?;
+
+    Ok(invoke_pools(
+        &env,
+        pools,
+        "ramp_a",
+        vec![&env, future_a.into_val(&env), future_time.into_val(&env)],
+    ))
+}
+
+pub fn stop_pools_a_ramp(env: Env, pools: Vec<Address>) -> Result<PoolsResult, Error> {
+    Admin::require_exist_auth(&env)?;
+
+    Ok(invoke_pools(&env, pools, "stop_ramp_a", Vec::new(&env)))
+}
+
+/// Calls `func` on every pool, a failed call is reported instead of aborting the batch
+pub(crate) fn invoke_pools(
+    env: &Env,
+    pools: Vec<Address>,
+    func: &str,
+    args: Vec<Val>,
+) -> PoolsResult {
+    let func = Symbol::new(env, func);
+    let mut results = Vec::new(env);
+
+    for pool in pools.iter() {
+        let result = env.try_invoke_contract::<(), Error>(&pool, &func, args.clone());
+        results.push_back((pool, matches!(result, Ok(Ok(())))));
+    }
+
+    results
+}
@@ -1,7 +1,15 @@
+mod admin;
 mod create_pair;
 mod initialize;
+mod manage_pools;
+mod protocol_fee;
+mod remove_pair;
 mod view;
 
-pub use create_pair::create_pair;
+pub use admin::*;
+pub use create_pair::*;
 pub use initialize::initialize;
+pub use manage_pools::*;
+pub use protocol_fee::*;
+pub use remove_pair::*;
 pub use view::*;
@@ -0,0 +1,56 @@
+use shared::{soroban_data::SimpleSorobanData, Error};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol, Vec};
+
+use storage::Admin;
+
+use super::manage_pools::{invoke_pools, PoolsResult};
+use crate::storage::protocol_fee::ProtocolFee;
+
+/// (pool, claimed amounts) for every pool of the batch, `None` when the claim failed
+pub type CollectedFees = Vec<(Address, Option<(u128, u128)>)>;
+
+/// Claims accrued protocol fees of `pools` to `fee_to`, a failed claim is reported
+/// instead of aborting the batch
+pub fn collect_protocol_fees(env: Env, pools: Vec<Address>) -> Result<CollectedFees, Error> {
+    let protocol_fee = ProtocolFee::get(&env)?;
+    let func = Symbol::new(&env, "claim_protocol_fee");
+    let args = vec![&env, protocol_fee.fee_to.into_val(&env)];
+    let mut collected = Vec::new(&env);
+
+    for pool in pools.iter() {
+        let result = env.try_invoke_contract::<(u128, u128), Error>(&pool, &func, args.clone());
+        collected.push_back((pool, result.ok().and_then(Result::ok)));
+    }
+
+    Ok(collected)
+}
+
+/// Pushes the current protocol fee share to `pools`, pools keep the last pushed share
+pub fn sync_pools_protocol_fee(env: Env, pools: Vec<Address>) -> Result<PoolsResult, Error> {
+    Admin::require_exist_auth(&env)?;
+    let share_bp = ProtocolFee::get(&env)?.share_bp;
+
+    Ok(invoke_pools(
+        &env,
+        pools,
+        "set_protocol_fee_share",
+        vec![&env, share_bp.into_val(&env)],
+    ))
+}
+
+/// Sets the protocol fee share of a newly created pool, a pool that fails to take it
+/// keeps no protocol fee until `sync_pools_protocol_fee`
+pub(crate) fn init_pool_protocol_fee(env: &Env, pool: &Address) -> Result<(), Error> {
+    let share_bp = ProtocolFee::get(env)?.share_bp;
+
+    if share_bp > 0 {
+        invoke_pools(
+            env,
+            vec![env, pool.clone()],
+            "set_protocol_fee_share",
+            vec![env, share_bp.into_val(env)],
+        );
+    }
+
+    Ok(())
+}
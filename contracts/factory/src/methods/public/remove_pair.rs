@@ -0,0 +1,156 @@
+use shared::{require, soroban_data::SimpleSorobanData, Error, Event};
+use soroban_sdk::{Address, Env, Map, Symbol, TryFromVal, Val, Vec};
+use storage::Admin;
+
+use crate::{
+    events::{PairRemoved, PairReplaced},
+    storage::{
+        factory_info::FactoryInfo,
+        pair_config::{PairConfig, PoolParams},
+        pool_record::{DeployedPool, PoolRecord},
+        token_info::TokenInfo,
+    },
+};
+
+/// Part of the state of a pool, read from its `get_pool`
+struct PoolState {
+    tokens: (Address, Address),
+    params: PoolParams,
+}
+
+pub fn remove_pair(
+    env: Env,
+    token_a: Address,
+    token_b: Address,
+    tier: u32,
+    withdraw_only: bool,
+) -> Result<Address, Error> {
+    Admin::require_exist_auth(&env)?;
+
+    let mut factory_info = FactoryInfo::get(&env)?;
+    let record = PoolRecord::remove(&env, &mut factory_info, &token_a, &token_b, tier)?;
+    factory_info.save(&env);
+
+    if withdraw_only {
+        pause_pool(&env, &record.pool);
+    }
+
+    PairRemoved {
+        token0: record.token_a,
+        token1: record.token_b,
+        tier,
+        pool: record.pool.clone(),
+    }
+    .publish(&env);
+
+    Ok(record.pool)
+}
+
+/// Registers an already deployed `pool` in place of the current pool of the tier.
+/// The pool must be deployed by the factory for the same tokens and not be registered,
+/// its params are recorded
+pub fn replace_pair(
+    env: Env,
+    token_a: Address,
+    token_b: Address,
+    tier: u32,
+    pool: Address,
+    withdraw_only: bool,
+) -> Result<Address, Error> {
+    Admin::require_exist_auth(&env)?;
+
+    require_allowed_asset(&env, &token_a)?;
+    require_allowed_asset(&env, &token_b)?;
+
+    // The pool is trusted only once it is known to be deployed by the factory
+    let DeployedPool(deployed_a, deployed_b) =
+        DeployedPool::get(&env, &pool).map_err(|_| Error::InvalidArg)?;
+    require!(
+        (deployed_a, deployed_b) == FactoryInfo::sort_tokens(token_a.clone(), token_b.clone()),
+        Error::InvalidArg
+    );
+    let state = PoolState::get(&env, &pool).ok_or(Error::InvalidArg)?;
+    require!(!state.is_registered(&env, &pool), Error::PairExist);
+    PairConfig::get(&env)?.validate_params(&state.params)?;
+
+    let mut factory_info = FactoryInfo::get(&env)?;
+    let old_record = PoolRecord::remove(&env, &mut factory_info, &token_a, &token_b, tier)?;
+    let params = state.params;
+
+    PoolRecord {
+        pool: pool.clone(),
+        token_a: old_record.token_a.clone(),
+        token_b: old_record.token_b.clone(),
+        tier,
+        params,
+    }
+    .add(&env, &mut factory_info);
+    factory_info.save(&env);
+
+    if withdraw_only {
+        pause_pool(&env, &old_record.pool);
+    }
+
+    PairReplaced {
+        token0: old_record.token_a,
+        token1: old_record.token_b,
+        tier,
+        old_pool: old_record.pool.clone(),
+        new_pool: pool,
+    }
+    .publish(&env);
+
+    Ok(old_record.pool)
+}
+
+/// Listed token or the LP of a registered pool, the asset of a metapool
+fn require_allowed_asset(env: &Env, token: &Address) -> Result<(), Error> {
+    match TokenInfo::require_allowed(env, token) {
+        Err(Error::TokenNotListed) => {
+            let is_base_pool =
+                PoolState::get(env, token).is_some_and(|state| state.is_registered(env, token));
+            require!(is_base_pool, Error::TokenNotListed);
+
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+impl PoolState {
+    /// `None` when `pool` is not a pool
+    fn get(env: &Env, pool: &Address) -> Option<PoolState> {
+        let state = env
+            .try_invoke_contract::<Map<Symbol, Val>, Error>(
+                pool,
+                &Symbol::new(env, "get_pool"),
+                Vec::new(env),
+            )
+            .ok()?
+            .ok()?;
+        let field = |name: &str| state.get(Symbol::new(env, name));
+        let tokens = Map::<Symbol, (Address, Address)>::try_from_val(env, &field("tokens")?)
+            .ok()?
+            .get(Symbol::new(env, "data"))?;
+
+        Some(PoolState {
+            tokens,
+            params: PoolParams {
+                a: u128::try_from_val(env, &field("a")?).ok()?,
+                fee_share_bp: u128::try_from_val(env, &field("fee_share_bp")?).ok()?,
+                admin_fee_share_bp: u128::try_from_val(env, &field("admin_fee_share_bp")?).ok()?,
+            },
+        })
+    }
+
+    /// `pool` with this state is in the registry
+    fn is_registered(&self, env: &Env, pool: &Address) -> bool {
+        PoolRecord::get_for_pair(env, &self.tokens.0, &self.tokens.1)
+            .iter()
+            .any(|record| record.pool == *pool)
+    }
+}
+
+fn pause_pool(env: &Env, pool: &Address) {
+    env.invoke_contract::<()>(pool, &Symbol::new(env, "pause"), Vec::new(env));
+}
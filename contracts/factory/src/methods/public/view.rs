@@ -1,16 +1,97 @@
-use shared::{soroban_data::SimpleSorobanData, utils::extend_ttl_instance, Error};
-use soroban_sdk::{Address, Env, Map};
+use shared::{require, soroban_data::SimpleSorobanData, utils::extend_ttl_instance, Error};
+use soroban_sdk::{Address, Env, Map, Vec};
 
-use crate::storage::factory_info::FactoryInfo;
+use crate::storage::{
+    factory_info::FactoryInfo, pair_config::PairConfig, pool_record::PoolRecord,
+    protocol_fee::ProtocolFee, token_info::TokenInfo,
+};
 
 pub fn get_pool(env: Env, token_a: &Address, token_b: &Address) -> Result<Address, Error> {
+    get_pool_by_tier(env, token_a, token_b, FactoryInfo::DEFAULT_TIER)
+}
+
+pub fn get_pool_by_tier(
+    env: Env,
+    token_a: &Address,
+    token_b: &Address,
+    tier: u32,
+) -> Result<Address, Error> {
+    extend_ttl_instance(&env);
+
+    Ok(PoolRecord::get_by_pair(&env, token_a, token_b, tier)?.pool)
+}
+
+pub fn get_pools_for_pair(
+    env: Env,
+    token_a: &Address,
+    token_b: &Address,
+) -> Result<Vec<PoolRecord>, Error> {
+    extend_ttl_instance(&env);
+
+    Ok(PoolRecord::get_for_pair(&env, token_a, token_b))
+}
+
+pub fn get_pools_count(env: Env) -> Result<u32, Error> {
+    extend_ttl_instance(&env);
+
+    Ok(FactoryInfo::get(&env)?.pools_count)
+}
+
+pub fn get_pools_page(env: Env, offset: u32, limit: u32) -> Result<Vec<PoolRecord>, Error> {
     extend_ttl_instance(&env);
 
-    FactoryInfo::get(&env)?.get_pool(token_a, token_b)
+    let pools_count = FactoryInfo::get(&env)?.pools_count;
+
+    Ok(PoolRecord::get_page(&env, pools_count, offset, limit))
 }
 
+/// Every pool with its tokens, reads the whole index
 pub fn get_pools(env: Env) -> Result<Map<Address, (Address, Address)>, Error> {
     extend_ttl_instance(&env);
 
-    FactoryInfo::get(&env)?.get_pools()
+    let pools_count = FactoryInfo::get(&env)?.pools_count;
+    let mut pools = Map::new(&env);
+
+    for index in 0..pools_count {
+        let record = PoolRecord::get_by_index(&env, index)?;
+        pools.set(record.pool, (record.token_a, record.token_b));
+    }
+
+    Ok(pools)
+}
+
+pub fn get_pool_by_index(env: Env, index: u32) -> Result<PoolRecord, Error> {
+    extend_ttl_instance(&env);
+
+    PoolRecord::get_by_index(&env, index)
+}
+
+pub fn predict_pool_address(
+    env: Env,
+    deployer: Address,
+    token_a: Address,
+    token_b: Address,
+    tier: u32,
+) -> Result<Address, Error> {
+    require!(token_a != token_b, Error::IdenticalAddresses);
+
+    let (token_a, token_b) = FactoryInfo::sort_tokens(token_a, token_b);
+    let salt = FactoryInfo::pool_salt(&token_a, &token_b, tier)?;
+
+    Ok(env
+        .deployer()
+        .with_address(deployer, salt)
+        .deployed_address())
+}
+
+pub fn get_pair_config(env: Env) -> Result<PairConfig, Error> {
+    PairConfig::get(&env)
+}
+
+pub fn get_protocol_fee(env: Env) -> Result<ProtocolFee, Error> {
+    ProtocolFee::get(&env)
+}
+
+pub fn get_token_info(env: Env, token: Address) -> Result<TokenInfo, Error> {
+    TokenInfo::get(&env, &token)
 }
@@ -3,22 +3,25 @@ use shared::{
     utils::{bytes::address_to_bytes, merge_slices_by_half},
     Error,
 };
-use soroban_sdk::{contracttype, Address, BytesN, Map};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN};
 
 #[contracttype]
 #[derive(SorobanData, SorobanSimpleData, SymbolKey, Instance)]
 #[extend_ttl_info_instance]
 pub struct FactoryInfo {
     pub wasm_hash: soroban_sdk::BytesN<32>,
-    /// (token0, token1) => pool
-    pub pairs: Map<(Address, Address), Address>,
+    /// Number of pools in the `PoolRecord` index
+    pub pools_count: u32,
 }
 
 impl FactoryInfo {
+    /// Tier of the pool returned by `pool(token_a, token_b)`
+    pub const DEFAULT_TIER: u32 = 0;
+
     pub fn new(wasm_hash: BytesN<32>) -> Self {
         FactoryInfo {
-            wasm_hash: wasm_hash.clone(),
-            pairs: Map::new(wasm_hash.env()),
+            wasm_hash,
+            pools_count: 0,
         }
     }
 
@@ -42,23 +45,12 @@ impl FactoryInfo {
         ))
     }
 
-    pub fn add_pair(&mut self, tokens: (Address, Address), pool: &Address) {
-        self.pairs.set(tokens, pool.clone());
-    }
-
-    pub fn get_pool(&self, token_a: &Address, token_b: &Address) -> Result<Address, Error> {
-        let (token_a, token_b) = FactoryInfo::sort_tokens(token_a.clone(), token_b.clone());
-
-        self.pairs.get((token_a, token_b)).ok_or(Error::NotFound)
-    }
-
-    pub fn get_pools(&self) -> Result<Map<Address, (Address, Address)>, Error> {
-        let mut map = Map::new(self.pairs.env());
-
-        self.pairs.iter().for_each(|(tokens, pool)| {
-            map.set(pool, tokens);
-        });
+    /// keccak256(token0 || token1 || tier), tokens are expected to be sorted
+    pub fn pool_salt(token0: &Address, token1: &Address, tier: u32) -> Result<BytesN<32>, Error> {
+        let env = token0.env();
+        let mut bytes: Bytes = Self::merge_addresses(token0, token1)?.into();
+        bytes.extend_from_array(&tier.to_be_bytes());
 
-        Ok(map)
+        Ok(env.crypto().keccak256(&bytes))
     }
 }
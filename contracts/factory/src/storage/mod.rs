@@ -1 +1,5 @@
 pub mod factory_info;
+pub mod pair_config;
+pub mod pool_record;
+pub mod protocol_fee;
+pub mod token_info;
@@ -0,0 +1,118 @@
+use proc_macros::{extend_ttl_info_instance, Instance, SorobanData, SorobanSimpleData, SymbolKey};
+use shared::{require, Error};
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Debug, Clone)]
+pub struct PoolParams {
+    pub a: u128,
+    pub fee_share_bp: u128,
+    pub admin_fee_share_bp: u128,
+}
+
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, SorobanSimpleData, SymbolKey, Instance)]
+#[extend_ttl_info_instance]
+pub struct PairConfig {
+    /// Allows anyone to create a pair with `default_params`
+    pub permissionless: bool,
+    pub default_params: PoolParams,
+    /// (min, max), inclusive
+    pub a_range: (u128, u128),
+    /// (min, max), inclusive
+    pub fee_share_bp_range: (u128, u128),
+    /// (min, max), inclusive
+    pub admin_fee_share_bp_range: (u128, u128),
+    /// Passed to new pools, validated by the pool on `initialize`
+    pub system_precision: u32,
+    /// Passed to new pools, validated by the pool on `initialize`
+    pub max_token_balance: u128,
+    /// Staleness bound (seconds) of the cached rates in new pools with rate providers
+    pub rate_max_age: u64,
+}
+
+impl Default for PairConfig {
+    fn default() -> Self {
+        PairConfig {
+            permissionless: false,
+            default_params: PoolParams {
+                a: 20,
+                fee_share_bp: 15,
+                admin_fee_share_bp: 2000,
+            },
+            a_range: (1, u128::MAX),
+            fee_share_bp_range: (0, PairConfig::BP - 1),
+            admin_fee_share_bp_range: (0, PairConfig::BP - 1),
+            system_precision: 3,
+            max_token_balance: 2u128.pow(40),
+            rate_max_age: 300,
+        }
+    }
+}
+
+impl PairConfig {
+    pub const BP: u128 = 10000;
+
+    pub fn set_ranges(
+        &mut self,
+        a_range: (u128, u128),
+        fee_share_bp_range: (u128, u128),
+        admin_fee_share_bp_range: (u128, u128),
+    ) -> Result<(), Error> {
+        for (min, max) in [a_range, fee_share_bp_range, admin_fee_share_bp_range] {
+            require!(min <= max, Error::InvalidArg);
+        }
+
+        require!(a_range.0 > 0, Error::InvalidArg);
+        require!(fee_share_bp_range.1 < Self::BP, Error::InvalidArg);
+        require!(admin_fee_share_bp_range.1 < Self::BP, Error::InvalidArg);
+
+        self.a_range = a_range;
+        self.fee_share_bp_range = fee_share_bp_range;
+        self.admin_fee_share_bp_range = admin_fee_share_bp_range;
+
+        self.validate_params(&self.default_params)
+    }
+
+    pub fn set_default_params(&mut self, params: PoolParams) -> Result<(), Error> {
+        self.validate_params(&params)?;
+        self.default_params = params;
+
+        Ok(())
+    }
+
+    pub fn validate_params(&self, params: &PoolParams) -> Result<(), Error> {
+        self.validate_a(params.a)?;
+        self.validate_fee_share_bp(params.fee_share_bp)?;
+        self.validate_admin_fee_share_bp(params.admin_fee_share_bp)
+    }
+
+    pub fn validate_a(&self, a: u128) -> Result<(), Error> {
+        require!(Self::in_range(a, self.a_range), Error::InvalidArg);
+
+        Ok(())
+    }
+
+    pub fn validate_fee_share_bp(&self, fee_share_bp: u128) -> Result<(), Error> {
+        require!(
+            Self::in_range(fee_share_bp, self.fee_share_bp_range),
+            Error::InvalidArg
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_admin_fee_share_bp(&self, admin_fee_share_bp: u128) -> Result<(), Error> {
+        require!(
+            Self::in_range(admin_fee_share_bp, self.admin_fee_share_bp_range),
+            Error::InvalidArg
+        );
+
+        Ok(())
+    }
+
+    #[inline]
+    fn in_range(value: u128, (min, max): (u128, u128)) -> bool {
+        min <= value && value <= max
+    }
+}
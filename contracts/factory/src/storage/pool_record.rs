@@ -0,0 +1,193 @@
+use proc_macros::{extend_ttl_info, Persistent, SorobanData};
+use shared::{consts::DAY_IN_LEDGERS, soroban_data::SorobanData, Error};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use super::{factory_info::FactoryInfo, pair_config::PoolParams, token_info::TokenPools};
+
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+enum PoolKey {
+    /// index => PoolRecord
+    Index(u32),
+    /// (token0, token1, tier) => PoolIndex
+    Pair(Address, Address, u32),
+    /// (token0, token1) => PairTiers
+    Tiers(Address, Address),
+    /// pool => DeployedPool
+    Deployed(Address),
+}
+
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct PoolRecord {
+    pub pool: Address,
+    /// Tokens are sorted
+    pub token_a: Address,
+    pub token_b: Address,
+    pub tier: u32,
+    /// Params the pool was created with
+    pub params: PoolParams,
+}
+
+#[contracttype]
+#[derive(SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+struct PoolIndex(u32);
+
+/// Tiers of a pair in the order they were registered, a replaced tier moves to the end
+#[contracttype]
+#[derive(SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+struct PairTiers(Vec<u32>);
+
+/// Sorted tokens of a pool deployed by the factory, kept once the pool is removed
+#[contracttype]
+#[derive(SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct DeployedPool(pub Address, pub Address);
+
+impl DeployedPool {
+    pub fn get(env: &Env, pool: &Address) -> Result<DeployedPool, Error> {
+        DeployedPool::get_by_key(env, &PoolKey::Deployed(pool.clone())).map_err(|_| Error::NotFound)
+    }
+
+    pub fn save(&self, env: &Env, pool: &Address) {
+        self.save_by_key(env, &PoolKey::Deployed(pool.clone()));
+    }
+}
+
+impl PoolRecord {
+    pub const MAX_PAGE_SIZE: u32 = 50;
+
+    pub fn get_by_index(env: &Env, index: u32) -> Result<PoolRecord, Error> {
+        PoolRecord::get_by_key(env, &PoolKey::Index(index)).map_err(|_| Error::NotFound)
+    }
+
+    pub fn get_by_pair(
+        env: &Env,
+        token_a: &Address,
+        token_b: &Address,
+        tier: u32,
+    ) -> Result<PoolRecord, Error> {
+        let (token_a, token_b) = FactoryInfo::sort_tokens(token_a.clone(), token_b.clone());
+        let PoolIndex(index) = PoolIndex::get_by_key(env, &PoolKey::Pair(token_a, token_b, tier))
+            .map_err(|_| Error::NotFound)?;
+
+        PoolRecord::get_by_index(env, index)
+    }
+
+    pub fn has_pair(env: &Env, token_a: &Address, token_b: &Address, tier: u32) -> bool {
+        let (token_a, token_b) = FactoryInfo::sort_tokens(token_a.clone(), token_b.clone());
+
+        PoolIndex::has_by_key(env, PoolKey::Pair(token_a, token_b, tier))
+    }
+
+    pub fn get_for_pair(env: &Env, token_a: &Address, token_b: &Address) -> Vec<PoolRecord> {
+        let (token_a, token_b) = FactoryInfo::sort_tokens(token_a.clone(), token_b.clone());
+        let mut records = Vec::new(env);
+
+        if let Ok(PairTiers(tiers)) =
+            PairTiers::get_by_key(env, &PoolKey::Tiers(token_a.clone(), token_b.clone()))
+        {
+            for tier in tiers.iter() {
+                if let Ok(record) = PoolRecord::get_by_pair(env, &token_a, &token_b, tier) {
+                    records.push_back(record);
+                }
+            }
+        }
+
+        records
+    }
+
+    /// Returns at most `MAX_PAGE_SIZE` records starting from `offset`
+    pub fn get_page(env: &Env, pools_count: u32, offset: u32, limit: u32) -> Vec<PoolRecord> {
+        let end = offset
+            .saturating_add(limit.min(Self::MAX_PAGE_SIZE))
+            .min(pools_count);
+        let mut records = Vec::new(env);
+
+        for index in offset..end {
+            if let Ok(record) = PoolRecord::get_by_index(env, index) {
+                records.push_back(record);
+            }
+        }
+
+        records
+    }
+
+    /// Appends the record to the index, returns its index
+    pub fn add(&self, env: &Env, factory_info: &mut FactoryInfo) -> u32 {
+        let index = factory_info.pools_count;
+        let tiers_key = PoolKey::Tiers(self.token_a.clone(), self.token_b.clone());
+        let mut tiers = PairTiers::get_by_key(env, &tiers_key)
+            .map(|PairTiers(tiers)| tiers)
+            .unwrap_or_else(|_| Vec::new(env));
+        tiers.push_back(self.tier);
+
+        self.save_by_key(env, &PoolKey::Index(index));
+        PoolIndex(index).save_by_key(
+            env,
+            &PoolKey::Pair(self.token_a.clone(), self.token_b.clone(), self.tier),
+        );
+        PairTiers(tiers).save_by_key(env, &tiers_key);
+
+        let pair = (self.token_a.clone(), self.token_b.clone(), self.tier);
+        TokenPools::add(env, &self.token_a, pair.clone());
+        TokenPools::add(env, &self.token_b, pair);
+
+        factory_info.pools_count += 1;
+
+        index
+    }
+
+    /// Removes the record from the index, the last record takes its place
+    pub fn remove(
+        env: &Env,
+        factory_info: &mut FactoryInfo,
+        token_a: &Address,
+        token_b: &Address,
+        tier: u32,
+    ) -> Result<PoolRecord, Error> {
+        let (token_a, token_b) = FactoryInfo::sort_tokens(token_a.clone(), token_b.clone());
+        let pair_key = PoolKey::Pair(token_a.clone(), token_b.clone(), tier);
+        let PoolIndex(index) =
+            PoolIndex::get_by_key(env, &pair_key).map_err(|_| Error::NotFound)?;
+        let record = PoolRecord::get_by_index(env, index)?;
+        let last_index = factory_info.pools_count - 1;
+
+        if index != last_index {
+            let last = PoolRecord::get_by_index(env, last_index)?;
+
+            last.save_by_key(env, &PoolKey::Index(index));
+            PoolIndex(index).save_by_key(
+                env,
+                &PoolKey::Pair(last.token_a.clone(), last.token_b.clone(), last.tier),
+            );
+        }
+
+        PoolRecord::remove_by_key(env, &PoolKey::Index(last_index));
+        PoolIndex::remove_by_key(env, &pair_key);
+
+        let tiers_key = PoolKey::Tiers(token_a.clone(), token_b.clone());
+        let PairTiers(mut tiers) = PairTiers::get_by_key(env, &tiers_key)?;
+        if let Some(tier_index) = tiers.first_index_of(tier) {
+            tiers.remove(tier_index);
+        }
+        if tiers.is_empty() {
+            PairTiers::remove_by_key(env, &tiers_key);
+        } else {
+            PairTiers(tiers).save_by_key(env, &tiers_key);
+        }
+
+        let pair = (token_a.clone(), token_b.clone(), tier);
+        TokenPools::remove(env, &token_a, &pair);
+        TokenPools::remove(env, &token_b, &pair);
+
+        factory_info.pools_count = last_index;
+
+        Ok(record)
+    }
+}
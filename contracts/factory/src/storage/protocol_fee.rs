@@ -0,0 +1,17 @@
+use proc_macros::{extend_ttl_info_instance, Instance, SorobanData, SorobanSimpleData, SymbolKey};
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, SorobanSimpleData, SymbolKey, Instance)]
+#[extend_ttl_info_instance]
+pub struct ProtocolFee {
+    /// Share of every pool fee reserved for the protocol, taken before the pool admin fee
+    pub share_bp: u128,
+    /// Recipient of `collect_protocol_fees`
+    pub fee_to: Address,
+}
+
+impl ProtocolFee {
+    /// Same cap as the pools apply to the pushed share
+    pub const MAX_SHARE_BP: u128 = 5000;
+}
@@ -0,0 +1,112 @@
+use proc_macros::{extend_ttl_info, Persistent, SorobanData};
+use shared::{consts::DAY_IN_LEDGERS, require, soroban_data::SorobanData, Error};
+use soroban_sdk::{contracttype, token, Address, Env, Vec};
+
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+enum TokenKey {
+    /// token => TokenInfo
+    Info(Address),
+    /// token => TokenPools
+    Pools(Address),
+    /// token => TokenRateProvider
+    RateProvider(Address),
+}
+
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct TokenInfo {
+    /// Expected `decimals()` of the token
+    pub decimals: u32,
+    pub stable: bool,
+    /// Blocked tokens can't be listed again
+    pub blocked: bool,
+}
+
+/// (token0, token1, tier) of every registered pool with the token
+#[contracttype]
+#[derive(SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct TokenPools(pub Vec<(Address, Address, u32)>);
+
+/// Rate provider of a yield-bearing or wrapped token, set on the pools created from now on
+#[contracttype]
+#[derive(SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct TokenRateProvider(pub Address);
+
+impl TokenInfo {
+    pub fn get(env: &Env, token: &Address) -> Result<TokenInfo, Error> {
+        TokenInfo::get_by_key(env, &TokenKey::Info(token.clone()))
+            .map_err(|_| Error::TokenNotListed)
+    }
+
+    pub fn save(&self, env: &Env, token: &Address) {
+        self.save_by_key(env, &TokenKey::Info(token.clone()));
+    }
+
+    /// Token is listed, not blocked and its decimals match the registry
+    pub fn require_allowed(env: &Env, token: &Address) -> Result<(), Error> {
+        let token_info = TokenInfo::get(env, token)?;
+
+        require!(!token_info.blocked, Error::TokenBlocked);
+        require!(
+            token::Client::new(env, token).decimals() == token_info.decimals,
+            Error::TokenDecimalsMismatch
+        );
+
+        Ok(())
+    }
+}
+
+impl TokenPools {
+    pub fn get(env: &Env, token: &Address) -> TokenPools {
+        TokenPools::get_by_key(env, &TokenKey::Pools(token.clone()))
+            .unwrap_or_else(|_| TokenPools(Vec::new(env)))
+    }
+
+    pub fn save(&self, env: &Env, token: &Address) {
+        let key = TokenKey::Pools(token.clone());
+
+        if self.0.is_empty() {
+            TokenPools::remove_by_key(env, &key);
+        } else {
+            self.save_by_key(env, &key);
+        }
+    }
+
+    pub fn add(env: &Env, token: &Address, pair: (Address, Address, u32)) {
+        let mut token_pools = TokenPools::get(env, token);
+        token_pools.0.push_back(pair);
+        token_pools.save(env, token);
+    }
+
+    pub fn remove(env: &Env, token: &Address, pair: &(Address, Address, u32)) {
+        let mut token_pools = TokenPools::get(env, token);
+
+        if let Some(index) = token_pools.0.first_index_of(pair) {
+            token_pools.0.remove(index);
+            token_pools.save(env, token);
+        }
+    }
+}
+
+impl TokenRateProvider {
+    pub fn get(env: &Env, token: &Address) -> Option<Address> {
+        TokenRateProvider::get_by_key(env, &TokenKey::RateProvider(token.clone()))
+            .ok()
+            .map(|TokenRateProvider(rate_provider)| rate_provider)
+    }
+
+    pub fn set(env: &Env, token: &Address, rate_provider: Option<Address>) {
+        let key = TokenKey::RateProvider(token.clone());
+
+        match rate_provider {
+            Some(rate_provider) => TokenRateProvider(rate_provider).save_by_key(env, &key),
+            None => TokenRateProvider::remove_by_key(env, &key),
+        }
+    }
+}
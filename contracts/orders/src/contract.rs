@@ -0,0 +1,142 @@
+use shared::{utils::extend_ttl_instance, Error};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+use crate::{
+    methods::{
+        public::{
+            cancel_long_term_order, cancel_order, claim_order, execute_long_term_orders,
+            initialize, place_long_term_order, place_order, swap, withdraw_long_term_proceeds,
+        },
+        view::{get_long_term_order, get_order, get_order_book, get_pool, get_twamm},
+    },
+    storage::{
+        common::{Direction, Token},
+        order::{LimitOrder, OrderBook},
+        twamm::{LongTermOrder, Twamm},
+    },
+};
+
+/// Limit and long-term orders of a pool, kept out of the pool contract.
+/// The orders are only filled and sold by the calls of this contract,
+/// swaps made on the pool directly do not see them
+#[contract]
+pub struct OrdersContract;
+
+#[contractimpl]
+impl OrdersContract {
+    /// Binds the contract to `pool`, metapools are not supported
+    pub fn initialize(env: Env, pool: Address) -> Result<(), Error> {
+        initialize(env, pool)
+    }
+
+    /// Runs the pending long-term sales, fills the resting orders giving more than the curve,
+    /// best price first, and swaps the rest on the pool. Returns the received amount
+    pub fn swap(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        amount: u128,
+        receive_amount_min: u128,
+        direction: Direction,
+    ) -> Result<u128, Error> {
+        extend_ttl_instance(&env);
+
+        swap(env, sender, recipient, amount, receive_amount_min, direction)
+    }
+
+    /// Escrows `amount` of `sell_token` sold at `price_bp` of the other token per unit
+    /// in basis points (9500 to 10500). Swaps of this contract fill the order when it gives
+    /// more than the curve. Returns the order id
+    pub fn place_order(
+        env: Env,
+        owner: Address,
+        sell_token: Token,
+        price_bp: u32,
+        amount: u128,
+    ) -> Result<u64, Error> {
+        extend_ttl_instance(&env);
+
+        place_order(env, owner, sell_token, price_bp, amount)
+    }
+
+    /// Returns the unfilled amount and the proceeds of the order to the owner
+    pub fn cancel_order(env: Env, owner: Address, id: u64) -> Result<(u128, u128), Error> {
+        extend_ttl_instance(&env);
+
+        cancel_order(env, owner, id)
+    }
+
+    /// Sends the proceeds of the order to the owner
+    pub fn claim_order(env: Env, owner: Address, id: u64) -> Result<u128, Error> {
+        extend_ttl_instance(&env);
+
+        claim_order(env, owner, id)
+    }
+
+    /// Escrows `amount` of `sell_token` sold on the pool at a constant rate until the end
+    /// of the `intervals`-th day from now (1 to 30), executed lazily by the calls of this
+    /// contract. Sales the pool fails to swap are refunded. Returns the order id.
+    /// Calls made on the pool directly do not execute the sales, so they are priced before
+    /// the pending sales, and all sales since the last execution move the price at once
+    pub fn place_long_term_order(
+        env: Env,
+        owner: Address,
+        sell_token: Token,
+        amount: u128,
+        intervals: u32,
+    ) -> Result<u64, Error> {
+        extend_ttl_instance(&env);
+
+        place_long_term_order(env, owner, sell_token, amount, intervals)
+    }
+
+    /// Sends the proceeds and the refunded sales of the long-term order so far to the owner,
+    /// returns the proceeds
+    pub fn withdraw_long_term_proceeds(env: Env, owner: Address, id: u64) -> Result<u128, Error> {
+        extend_ttl_instance(&env);
+
+        withdraw_long_term_proceeds(env, owner, id)
+    }
+
+    /// Returns the unsold and refunded amount and the proceeds of the long-term order to the owner
+    pub fn cancel_long_term_order(
+        env: Env,
+        owner: Address,
+        id: u64,
+    ) -> Result<(u128, u128), Error> {
+        extend_ttl_instance(&env);
+
+        cancel_long_term_order(env, owner, id)
+    }
+
+    /// Swaps the pending long-term sales on the pool, callable by anyone
+    pub fn execute_long_term_orders(env: Env) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        execute_long_term_orders(env)
+    }
+
+    // -------- View --------
+
+    pub fn get_pool(env: Env) -> Result<Address, Error> {
+        get_pool(env)
+    }
+
+    pub fn get_order(env: Env, id: u64) -> Result<LimitOrder, Error> {
+        get_order(env, id)
+    }
+
+    /// Prices of the ticks with resting orders for each sell token
+    pub fn get_order_book(env: Env) -> OrderBook {
+        get_order_book(env)
+    }
+
+    pub fn get_long_term_order(env: Env, id: u64) -> Result<LongTermOrder, Error> {
+        get_long_term_order(env, id)
+    }
+
+    /// Sale rates and proceeds of the long-term orders as of their last execution
+    pub fn get_twamm(env: Env) -> Twamm {
+        get_twamm(env)
+    }
+}
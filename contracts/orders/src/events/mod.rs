@@ -0,0 +1,73 @@
+use soroban_sdk::{contracttype, Address};
+
+use proc_macros::Event;
+
+/// Part of a swap filled by the resting orders, the rest is swapped by the pool
+#[derive(Event)]
+#[contracttype]
+pub struct OrdersFilled {
+    pub sender: Address,
+    pub from_token: Address,
+    pub to_token: Address,
+    pub from_amount: u128,
+    pub to_amount: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct OrderPlaced {
+    pub id: u64,
+    pub owner: Address,
+    pub sell_token: Address,
+    pub price_bp: u32,
+    pub amount: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct OrderCancelled {
+    pub id: u64,
+    pub owner: Address,
+    /// Sell token amount returned, unsold or refunded
+    pub amount: u128,
+    pub proceeds: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct OrderClaimed {
+    pub id: u64,
+    pub owner: Address,
+    pub proceeds: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct LongTermOrderPlaced {
+    pub id: u64,
+    pub owner: Address,
+    pub sell_token: Address,
+    pub amount: u128,
+    pub sale_rate: u128,
+    pub expiry: u64,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct LongTermOrderCancelled {
+    pub id: u64,
+    pub owner: Address,
+    /// Sell token amount returned
+    pub amount: u128,
+    pub proceeds: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct LongTermProceedsWithdrawn {
+    pub id: u64,
+    pub owner: Address,
+    pub proceeds: u128,
+    /// Sell token amount of the sales that could not be swapped
+    pub refund: u128,
+}
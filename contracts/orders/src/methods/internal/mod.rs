@@ -0,0 +1,3 @@
+pub mod order;
+pub mod pool;
+pub mod twamm;
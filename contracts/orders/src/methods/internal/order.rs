@@ -0,0 +1,86 @@
+use shared::{soroban_data::SimpleSorobanData, Error};
+use soroban_sdk::Env;
+
+use crate::storage::{
+    common::Token,
+    order::{LimitOrder, OrderBook, OrderTick},
+};
+
+use super::pool::PoolState;
+
+impl PoolState {
+    /// Fills the resting orders selling the output token that give more than the curve
+    /// for the same input, best price first. Returns the input taken by the orders
+    /// and their output
+    pub fn fill_orders(
+        &self,
+        env: &Env,
+        amount: u128,
+        token_from: Token,
+    ) -> Result<(u128, u128), Error> {
+        let token_to = token_from.opposite();
+        let mut order_book = OrderBook::get_or_default(env);
+
+        if order_book.ticks(token_to).is_empty() {
+            return Ok((0, 0));
+        }
+
+        let mut input_sp = self.amount_to_system_precision(amount, token_from)?;
+        let mut total_input = 0;
+        let mut total_output = 0;
+        let mut fills = 0;
+        let mut is_done = false;
+
+        while let Some(price_bp) = order_book.ticks(token_to).first() {
+            let price = price_bp as u128;
+            let mut tick = OrderTick::get(env, token_to, price_bp);
+
+            while let Some(id) = tick.0.first() {
+                if input_sp == 0 || fills == OrderBook::MAX_FILLS {
+                    is_done = true;
+                    break;
+                }
+
+                let mut order = LimitOrder::get(env, id)?;
+                let order_sp = self.amount_to_system_precision(order.amount, token_to)?;
+                let in_sp = input_sp.min((order_sp * price).div_ceil(PoolState::BP));
+                let out_sp = order_sp.min(in_sp * PoolState::BP / price);
+
+                let input = self.amount_from_system_precision(in_sp, token_from)?;
+                let output = self.amount_from_system_precision(out_sp, token_to)?;
+                let curve_output = self.get_receive_amount(env, input, token_from);
+
+                if output == 0 || output < curve_output {
+                    is_done = true;
+                    break;
+                }
+
+                order.amount -= output;
+                order.proceeds += input;
+                order.save(env, id);
+
+                input_sp -= in_sp;
+                total_input += input;
+                total_output += output;
+                fills += 1;
+
+                // Less than the system precision is left to the owner
+                if self.amount_to_system_precision(order.amount, token_to)? == 0 {
+                    tick.0.pop_front();
+                }
+            }
+
+            tick.save(env, token_to, price_bp);
+
+            if is_done {
+                break;
+            }
+
+            order_book.ticks_mut(token_to).pop_front();
+        }
+
+        order_book.save(env);
+
+        Ok((total_input, total_output))
+    }
+}
@@ -0,0 +1,166 @@
+use core::cmp::Ordering;
+
+use ethnum::U256;
+use shared::{soroban_data::SimpleSorobanData, utils::safe_cast, Error};
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    token, vec, Address, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::storage::{
+    common::{Direction, RateSource, Token},
+    config::Config,
+    double_values::{DoubleAddress, DoubleU128, DoubleU32},
+};
+
+/// Part of the state of the pool, read from its `get_pool`
+pub struct PoolState {
+    pub address: Address,
+    pub tokens: DoubleAddress,
+    pub tokens_decimals: DoubleU32,
+    pub system_precision: u32,
+    pub max_token_balance: u128,
+    pub token_balances: DoubleU128,
+    /// Rates cached by the pool at its last sync
+    pub rates: DoubleU128,
+    pub paused: bool,
+    pub is_metapool: bool,
+}
+
+impl PoolState {
+    pub const BP: u128 = 10000;
+
+    /// Rates are fixed point numbers with 18 decimals
+    pub const RATE_ONE: u128 = 1_000_000_000_000_000_000;
+
+    /// State of the pool of the orders
+    pub fn get(env: &Env) -> Result<PoolState, Error> {
+        let config = Config::get(env)?;
+
+        PoolState::read(env, &config.pool).ok_or(Error::BrokenAddress)
+    }
+
+    /// `None` when `pool` is not a pool
+    pub fn read(env: &Env, pool: &Address) -> Option<PoolState> {
+        let state = env
+            .try_invoke_contract::<Map<Symbol, Val>, Error>(
+                pool,
+                &Symbol::new(env, "get_pool"),
+                Vec::new(env),
+            )
+            .ok()?
+            .ok()?;
+        let field = |name: &str| state.get(Symbol::new(env, name));
+        let rate_sources =
+            <(RateSource, RateSource)>::try_from_val(env, &field("rate_sources")?).ok()?;
+
+        Some(PoolState {
+            address: pool.clone(),
+            tokens: DoubleAddress::try_from_val(env, &field("tokens")?).ok()?,
+            tokens_decimals: DoubleU32::try_from_val(env, &field("tokens_decimals")?).ok()?,
+            system_precision: u32::try_from_val(env, &field("system_precision")?).ok()?,
+            max_token_balance: u128::try_from_val(env, &field("max_token_balance")?).ok()?,
+            token_balances: DoubleU128::try_from_val(env, &field("token_balances")?).ok()?,
+            rates: DoubleU128::try_from_val(env, &field("rates")?).ok()?,
+            paused: bool::try_from_val(env, &field("paused")?).ok()?,
+            is_metapool: matches!(rate_sources.1, RateSource::BasePool(_)),
+        })
+    }
+
+    /// Curve output of the pool for `input`, 0 when it cannot be swapped
+    pub fn get_receive_amount(&self, env: &Env, input: u128, token_from: Token) -> u128 {
+        env.try_invoke_contract::<(u128, u128), Error>(
+            &self.address,
+            &Symbol::new(env, "get_receive_amount"),
+            vec![env, input.into_val(env), token_from.into_val(env)],
+        )
+        .map_or(0, |result| result.map_or(0, |(output, _)| output))
+    }
+
+    /// Swaps `amount` held by the contract on the pool, returns the received amount.
+    /// Nothing is swapped on an error of the pool
+    pub fn swap(&self, env: &Env, amount: u128, direction: Direction) -> Result<u128, Error> {
+        let current_contract = env.current_contract_address();
+        let (token_from, _) = direction.get_tokens();
+
+        // The pool takes the input from the contract on its behalf
+        env.authorize_as_current_contract(vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: self.tokens[token_from].clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (
+                        current_contract.clone(),
+                        self.address.clone(),
+                        safe_cast::<u128, i128>(amount)?,
+                    )
+                        .into_val(env),
+                },
+                sub_invocations: Vec::new(env),
+            }),
+        ]);
+
+        match env.try_invoke_contract::<u128, Error>(
+            &self.address,
+            &Symbol::new(env, "swap"),
+            vec![
+                env,
+                current_contract.clone().into_val(env),
+                current_contract.into_val(env),
+                amount.into_val(env),
+                0u128.into_val(env),
+                direction.into_val(env),
+            ],
+        ) {
+            Ok(Ok(output)) => Ok(output),
+            Err(Ok(error)) => Err(error),
+            // Traps of the pool, the curve math overflows on the balances it cannot take
+            _ => Err(Error::PoolOverflow),
+        }
+    }
+
+    pub fn transfer(
+        &self,
+        env: &Env,
+        token: Token,
+        from: &Address,
+        to: &Address,
+        amount: u128,
+    ) -> Result<(), Error> {
+        token::Client::new(env, &self.tokens[token]).transfer(from, to, &safe_cast(amount)?);
+
+        Ok(())
+    }
+
+    /// Token amount to the rated system precision amount of the pool
+    pub fn amount_to_system_precision(&self, amount: u128, token: Token) -> Result<u128, Error> {
+        let decimals = self.tokens_decimals[token];
+        let amount = match decimals.cmp(&self.system_precision) {
+            Ordering::Greater => amount / (10u128.pow(decimals - self.system_precision)),
+            Ordering::Less => amount
+                .checked_mul(10u128.pow(self.system_precision - decimals))
+                .ok_or(Error::PoolOverflow)?,
+            Ordering::Equal => amount,
+        };
+
+        safe_cast(U256::new(amount) * U256::new(self.rates[token]) / U256::new(Self::RATE_ONE))
+            .map_err(|_| Error::PoolOverflow)
+    }
+
+    /// Rated system precision amount of the pool to the token amount
+    pub fn amount_from_system_precision(&self, amount: u128, token: Token) -> Result<u128, Error> {
+        let decimals = self.tokens_decimals[token];
+        let amount: u128 =
+            safe_cast(U256::new(amount) * U256::new(Self::RATE_ONE) / U256::new(self.rates[token]))
+                .map_err(|_| Error::PoolOverflow)?;
+
+        match decimals.cmp(&self.system_precision) {
+            Ordering::Greater => amount
+                .checked_mul(10u128.pow(decimals - self.system_precision))
+                .ok_or(Error::PoolOverflow),
+            Ordering::Less => Ok(amount / (10u128.pow(self.system_precision - decimals))),
+            Ordering::Equal => Ok(amount),
+        }
+    }
+}
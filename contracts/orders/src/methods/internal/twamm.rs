@@ -0,0 +1,93 @@
+use ethnum::U256;
+use shared::{soroban_data::SimpleSorobanData, utils::safe_cast, Error};
+use soroban_sdk::Env;
+
+use crate::storage::{
+    common::Token,
+    twamm::{Twamm, TwammExpiry},
+};
+
+use super::pool::PoolState;
+
+impl PoolState {
+    /// Swaps the sales of the long-term orders since their last execution on the pool,
+    /// split at every expiry passed since. Nothing is executed while the pool is paused.
+    /// A sale the pool fails to swap or that takes its balances past `max_token_balance`
+    /// is skipped and refunded to its orders. The state is read again after the sales
+    pub fn execute_long_term_orders(&mut self, env: &Env) -> Result<(), Error> {
+        let Ok(mut twamm) = Twamm::get(env) else {
+            return Ok(());
+        };
+
+        let now = env.ledger().timestamp();
+
+        if self.paused || twamm.last_execution >= now {
+            return Ok(());
+        }
+
+        while twamm.last_execution < now {
+            let expiry = twamm.expiries.first().filter(|expiry| *expiry <= now);
+            let until = expiry.unwrap_or(now);
+
+            let duration = until - twamm.last_execution;
+            self.execute_sales(env, &mut twamm, duration)?;
+            twamm.last_execution = until;
+
+            if let Some(expiry) = expiry {
+                twamm.expiries.pop_front();
+
+                let mut twamm_expiry = TwammExpiry::get(env, expiry);
+                for token in [Token::A, Token::B] {
+                    twamm.sale_rates[token] -= twamm_expiry.sale_rates[token];
+                }
+                twamm_expiry.acc_proceeds_p = twamm.acc_proceeds_p.clone();
+                twamm_expiry.acc_refunds_p = twamm.acc_refunds_p.clone();
+                twamm_expiry.save(env, expiry);
+            }
+        }
+
+        twamm.save(env);
+
+        Ok(())
+    }
+
+    /// Swap of `duration` seconds of sales for each sell token
+    fn execute_sales(&mut self, env: &Env, twamm: &mut Twamm, duration: u64) -> Result<(), Error> {
+        for token in [Token::A, Token::B] {
+            let sale_rate = twamm.sale_rates[token];
+            let amount = sale_rate * duration as u128;
+
+            if amount == 0 {
+                continue;
+            }
+
+            // The curve math of the pool may overflow past `max_token_balance`
+            let fits = self
+                .amount_to_system_precision(amount, token)
+                .is_ok_and(|amount_sp| {
+                    self.token_balances.sum().saturating_add(amount_sp) < self.max_token_balance
+                });
+            let result = if fits {
+                self.swap(env, amount, token.into())
+            } else {
+                Err(Error::PoolOverflow)
+            };
+
+            match result {
+                Ok(output) => {
+                    *self = PoolState::read(env, &self.address).ok_or(Error::BrokenAddress)?;
+
+                    let proceeds_p = (U256::new(output) << Twamm::P) / U256::new(sale_rate);
+
+                    twamm.acc_proceeds_p[token] = safe_cast::<U256, u128>(proceeds_p)
+                        .ok()
+                        .and_then(|value| twamm.acc_proceeds_p[token].checked_add(value))
+                        .ok_or(Error::PoolOverflow)?;
+                }
+                Err(_) => twamm.acc_refunds_p[token] += (duration as u128) << Twamm::P,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use shared::{require, soroban_data::SimpleSorobanData, Error};
+use soroban_sdk::{Address, Env};
+
+use crate::{methods::internal::pool::PoolState, storage::config::Config};
+
+/// Binds the contract to `pool`. The base pool LP of a metapool moves with `transfer_lp`,
+/// not as a token, so metapools are not supported
+pub fn initialize(env: Env, pool: Address) -> Result<(), Error> {
+    require!(!Config::has(&env), Error::Initialized);
+
+    let state = PoolState::read(&env, &pool).ok_or(Error::InvalidArg)?;
+    require!(!state.is_metapool, Error::Forbidden);
+
+    Config { pool }.save(&env);
+
+    Ok(())
+}
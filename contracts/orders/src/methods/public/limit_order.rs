@@ -0,0 +1,227 @@
+use shared::{require, soroban_data::SimpleSorobanData, Error, Event};
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    events::{OrderCancelled, OrderClaimed, OrderPlaced},
+    methods::internal::pool::PoolState,
+    storage::{
+        common::Token,
+        order::{LimitOrder, OrderBook, OrderTick},
+    },
+};
+
+/// Escrows `amount` of `sell_token` to be sold at `price_bp` of the other token
+/// per unit in basis points, returns the order id.
+/// Once all ticks are taken, an order beating the worst tick price by `MIN_EVICTION_STEP_BP`
+/// and larger than all of its orders together evicts them
+pub fn place_order(
+    env: Env,
+    owner: Address,
+    sell_token: Token,
+    price_bp: u32,
+    amount: u128,
+) -> Result<u64, Error> {
+    owner.require_auth();
+    require!(
+        (OrderBook::MIN_PRICE_BP..=OrderBook::MAX_PRICE_BP).contains(&price_bp),
+        Error::InvalidArg
+    );
+
+    let pool = PoolState::get(&env)?;
+    require!(!pool.paused, Error::Paused);
+    require!(
+        pool.amount_to_system_precision(amount, sell_token)?
+            >= OrderBook::MIN_ORDER_AMOUNT * 10u128.pow(pool.system_precision),
+        Error::ZeroAmount
+    );
+
+    let mut order_book = OrderBook::get_or_default(&env);
+    let id = order_book.next_id;
+    let ticks = order_book.ticks_mut(sell_token);
+
+    if !ticks.contains(price_bp) {
+        if ticks.len() == OrderBook::MAX_TICKS {
+            let worst_price_bp = ticks.last().ok_or(Error::Forbidden)?;
+            require!(
+                price_bp + OrderBook::MIN_EVICTION_STEP_BP <= worst_price_bp,
+                Error::Forbidden
+            );
+
+            ticks.pop_back();
+            evict_tick(&env, &pool, sell_token, worst_price_bp, amount)?;
+        }
+
+        let index = ticks.iter().position(|tick| tick > price_bp);
+        ticks.insert(index.map_or(ticks.len(), |index| index as u32), price_bp);
+    }
+
+    let mut tick = OrderTick::get(&env, sell_token, price_bp);
+    require!(tick.0.len() < OrderBook::MAX_TICK_ORDERS, Error::Forbidden);
+    tick.0.push_back(id);
+    tick.save(&env, sell_token, price_bp);
+
+    order_book.next_id += 1;
+    order_book.save(&env);
+
+    pool.transfer(
+        &env,
+        sell_token,
+        &owner,
+        &env.current_contract_address(),
+        amount,
+    )?;
+
+    LimitOrder {
+        owner: owner.clone(),
+        sell_token,
+        price_bp,
+        amount,
+        proceeds: 0,
+    }
+    .save(&env, id);
+
+    OrderPlaced {
+        id,
+        owner,
+        sell_token: pool.tokens[sell_token].clone(),
+        price_bp,
+        amount,
+    }
+    .publish(&env);
+
+    Ok(id)
+}
+
+/// Removes the order and returns its unfilled amount and proceeds to the owner
+pub fn cancel_order(env: Env, owner: Address, id: u64) -> Result<(u128, u128), Error> {
+    owner.require_auth();
+
+    let pool = PoolState::get(&env)?;
+    let order = LimitOrder::get(&env, id)?;
+    require!(order.owner == owner, Error::Unauthorized);
+
+    let mut tick = OrderTick::get(&env, order.sell_token, order.price_bp);
+    if let Some(index) = tick.0.first_index_of(id) {
+        tick.0.remove(index);
+        tick.save(&env, order.sell_token, order.price_bp);
+
+        if tick.0.is_empty() {
+            let mut order_book = OrderBook::get_or_default(&env);
+            let ticks = order_book.ticks_mut(order.sell_token);
+
+            if let Some(index) = ticks.first_index_of(order.price_bp) {
+                ticks.remove(index);
+            }
+
+            order_book.save(&env);
+        }
+    }
+
+    LimitOrder::remove(&env, id);
+    pay_order(&env, &pool, &order, order.amount)?;
+
+    OrderCancelled {
+        id,
+        owner,
+        amount: order.amount,
+        proceeds: order.proceeds,
+    }
+    .publish(&env);
+
+    Ok((order.amount, order.proceeds))
+}
+
+/// Sends the proceeds of the order to the owner, a filled order is removed
+/// with the unfilled dust below the system precision
+pub fn claim_order(env: Env, owner: Address, id: u64) -> Result<u128, Error> {
+    owner.require_auth();
+
+    let pool = PoolState::get(&env)?;
+    let mut order = LimitOrder::get(&env, id)?;
+    require!(order.owner == owner, Error::Unauthorized);
+
+    let proceeds = order.proceeds;
+    let is_filled = pool.amount_to_system_precision(order.amount, order.sell_token)? == 0;
+
+    if is_filled {
+        LimitOrder::remove(&env, id);
+        pay_order(&env, &pool, &order, order.amount)?;
+    } else {
+        order.proceeds = 0;
+        order.save(&env, id);
+        pay_order(&env, &pool, &order, 0)?;
+    }
+
+    OrderClaimed {
+        id,
+        owner,
+        proceeds,
+    }
+    .publish(&env);
+
+    Ok(proceeds)
+}
+
+/// Cancels all orders of the tick for an order of `amount`, their owners are paid out.
+/// Fails unless the amount exceeds the unfilled amount of the tick
+fn evict_tick(
+    env: &Env,
+    pool: &PoolState,
+    sell_token: Token,
+    price_bp: u32,
+    amount: u128,
+) -> Result<(), Error> {
+    let tick = OrderTick::get(env, sell_token, price_bp);
+    let mut tick_amount = 0;
+
+    for id in tick.0.iter() {
+        tick_amount += LimitOrder::get(env, id)?.amount;
+    }
+
+    require!(amount > tick_amount, Error::Forbidden);
+
+    for id in tick.0.iter() {
+        let order = LimitOrder::get(env, id)?;
+        LimitOrder::remove(env, id);
+        pay_order(env, pool, &order, order.amount)?;
+
+        OrderCancelled {
+            id,
+            owner: order.owner,
+            amount: order.amount,
+            proceeds: order.proceeds,
+        }
+        .publish(env);
+    }
+
+    OrderTick(Vec::new(env)).save(env, sell_token, price_bp);
+
+    Ok(())
+}
+
+/// Pays `amount` of the sell token with the proceeds of `order` to its owner
+fn pay_order(env: &Env, pool: &PoolState, order: &LimitOrder, amount: u128) -> Result<(), Error> {
+    let current_contract = env.current_contract_address();
+
+    if amount > 0 {
+        pool.transfer(
+            env,
+            order.sell_token,
+            &current_contract,
+            &order.owner,
+            amount,
+        )?;
+    }
+
+    if order.proceeds > 0 {
+        pool.transfer(
+            env,
+            order.sell_token.opposite(),
+            &current_contract,
+            &order.owner,
+            order.proceeds,
+        )?;
+    }
+
+    Ok(())
+}
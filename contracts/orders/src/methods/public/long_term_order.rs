@@ -0,0 +1,222 @@
+use shared::{require, soroban_data::SimpleSorobanData, Error, Event};
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events::{LongTermOrderCancelled, LongTermOrderPlaced, LongTermProceedsWithdrawn},
+    methods::internal::pool::PoolState,
+    storage::{
+        common::Token,
+        twamm::{LongTermOrder, Twamm, TwammExpiry},
+    },
+};
+
+/// Escrows `amount` of `sell_token` sold on the curve at a constant rate until the end
+/// of the `intervals`-th interval from now, returns the order id.
+/// The part of `amount` not divisible by the duration is not taken.
+/// The sales are executed by swaps, deposits and long-term order changes only,
+/// a withdrawal leaves them pending
+pub fn place_long_term_order(
+    env: Env,
+    owner: Address,
+    sell_token: Token,
+    amount: u128,
+    intervals: u32,
+) -> Result<u64, Error> {
+    owner.require_auth();
+    require!(
+        (1..=Twamm::MAX_INTERVALS).contains(&intervals),
+        Error::InvalidArg
+    );
+
+    let mut pool = PoolState::get(&env)?;
+    require!(!pool.paused, Error::Paused);
+    pool.execute_long_term_orders(&env)?;
+
+    let now = env.ledger().timestamp();
+    let expiry = (now / Twamm::INTERVAL + intervals as u64) * Twamm::INTERVAL;
+    let duration = (expiry - now) as u128;
+    let sale_rate = amount / duration;
+    require!(sale_rate > 0, Error::ZeroAmount);
+
+    let amount = sale_rate * duration;
+    let mut twamm = Twamm::get_or_default(&env);
+    let id = twamm.next_id;
+    twamm.next_id += 1;
+    twamm.sale_rates[sell_token] += sale_rate;
+
+    if !twamm.expiries.contains(expiry) {
+        let index = twamm.expiries.iter().position(|item| item > expiry);
+        twamm.expiries.insert(
+            index.map_or(twamm.expiries.len(), |index| index as u32),
+            expiry,
+        );
+    }
+
+    let mut twamm_expiry = TwammExpiry::get(&env, expiry);
+    twamm_expiry.sale_rates[sell_token] += sale_rate;
+    twamm_expiry.orders += 1;
+    twamm_expiry.save(&env, expiry);
+
+    pool.transfer(
+        &env,
+        sell_token,
+        &owner,
+        &env.current_contract_address(),
+        amount,
+    )?;
+
+    LongTermOrder {
+        owner: owner.clone(),
+        sell_token,
+        sale_rate,
+        expiry,
+        acc_proceeds_p: twamm.acc_proceeds_p[sell_token],
+        acc_refunds_p: twamm.acc_refunds_p[sell_token],
+    }
+    .save(&env, id);
+
+    twamm.save(&env);
+
+    LongTermOrderPlaced {
+        id,
+        owner,
+        sell_token: pool.tokens[sell_token].clone(),
+        amount,
+        sale_rate,
+        expiry,
+    }
+    .publish(&env);
+
+    Ok(id)
+}
+
+/// Sends the proceeds of the order so far to the owner with the sales refunded as they
+/// could not be swapped, an expired order is removed
+pub fn withdraw_long_term_proceeds(env: Env, owner: Address, id: u64) -> Result<u128, Error> {
+    owner.require_auth();
+
+    let pool = execute_pool(&env)?;
+    let twamm = Twamm::get_or_default(&env);
+    let mut order = LongTermOrder::get(&env, id)?;
+    require!(order.owner == owner, Error::Unauthorized);
+
+    let (proceeds, refund, remaining) = order.get_amounts(&env, &twamm)?;
+
+    if remaining == 0 {
+        remove_order(&env, &order, id);
+    } else {
+        order.acc_proceeds_p = twamm.acc_proceeds_p[order.sell_token];
+        order.acc_refunds_p = twamm.acc_refunds_p[order.sell_token];
+        order.save(&env, id);
+    }
+
+    pay_order(&env, &pool, &order, refund, proceeds)?;
+
+    LongTermProceedsWithdrawn {
+        id,
+        owner,
+        proceeds,
+        refund,
+    }
+    .publish(&env);
+
+    Ok(proceeds)
+}
+
+/// Stops the order and returns its unsold and refunded amount and proceeds to the owner
+pub fn cancel_long_term_order(env: Env, owner: Address, id: u64) -> Result<(u128, u128), Error> {
+    owner.require_auth();
+
+    let pool = execute_pool(&env)?;
+    let mut twamm = Twamm::get_or_default(&env);
+    let order = LongTermOrder::get(&env, id)?;
+    require!(order.owner == owner, Error::Unauthorized);
+
+    let (proceeds, refund, remaining) = order.get_amounts(&env, &twamm)?;
+
+    if remaining > 0 {
+        twamm.sale_rates[order.sell_token] -= order.sale_rate;
+
+        let mut twamm_expiry = TwammExpiry::get(&env, order.expiry);
+        twamm_expiry.sale_rates[order.sell_token] -= order.sale_rate;
+
+        if twamm_expiry.orders == 1 {
+            if let Some(index) = twamm.expiries.first_index_of(order.expiry) {
+                twamm.expiries.remove(index);
+            }
+        }
+
+        twamm_expiry.save(&env, order.expiry);
+        twamm.save(&env);
+    }
+
+    let amount = remaining + refund;
+    remove_order(&env, &order, id);
+    pay_order(&env, &pool, &order, amount, proceeds)?;
+
+    LongTermOrderCancelled {
+        id,
+        owner,
+        amount,
+        proceeds,
+    }
+    .publish(&env);
+
+    Ok((amount, proceeds))
+}
+
+/// Swaps the pending sales of the long-term orders on the pool
+pub fn execute_long_term_orders(env: Env) -> Result<(), Error> {
+    execute_pool(&env)?;
+
+    Ok(())
+}
+
+/// Pool with the long-term orders executed
+fn execute_pool(env: &Env) -> Result<PoolState, Error> {
+    let mut pool = PoolState::get(env)?;
+    pool.execute_long_term_orders(env)?;
+
+    Ok(pool)
+}
+
+fn remove_order(env: &Env, order: &LongTermOrder, id: u64) {
+    let mut twamm_expiry = TwammExpiry::get(env, order.expiry);
+    twamm_expiry.orders -= 1;
+    twamm_expiry.save(env, order.expiry);
+
+    LongTermOrder::remove(env, id);
+}
+
+/// Pays `amount` of the sell token and `proceeds` of the other one to the order owner
+fn pay_order(
+    env: &Env,
+    pool: &PoolState,
+    order: &LongTermOrder,
+    amount: u128,
+    proceeds: u128,
+) -> Result<(), Error> {
+    let current_contract = env.current_contract_address();
+
+    if amount > 0 {
+        pool.transfer(
+            env,
+            order.sell_token,
+            &current_contract,
+            &order.owner,
+            amount,
+        )?;
+    }
+
+    if proceeds > 0 {
+        pool.transfer(
+            env,
+            order.sell_token.opposite(),
+            &current_contract,
+            &order.owner,
+            proceeds,
+        )?;
+    }
+
+    Ok(())
+}
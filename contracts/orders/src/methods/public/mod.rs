@@ -0,0 +1,9 @@
+mod initialize;
+mod limit_order;
+mod long_term_order;
+mod swap;
+
+pub use initialize::*;
+pub use limit_order::*;
+pub use long_term_order::*;
+pub use swap::*;
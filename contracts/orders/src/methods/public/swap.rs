@@ -0,0 +1,57 @@
+use shared::{require, Error, Event};
+use soroban_sdk::{Address, Env};
+
+use crate::{events::OrdersFilled, methods::internal::pool::PoolState, storage::common::Direction};
+
+/// Runs the pending long-term sales, fills the resting orders beating the curve
+/// and swaps the rest on the pool
+pub fn swap(
+    env: Env,
+    sender: Address,
+    recipient: Address,
+    amount: u128,
+    receive_amount_min: u128,
+    direction: Direction,
+) -> Result<u128, Error> {
+    sender.require_auth();
+
+    let mut pool = PoolState::get(&env)?;
+    require!(!pool.paused, Error::Paused);
+    pool.execute_long_term_orders(&env)?;
+
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    let current_contract = env.current_contract_address();
+    let (token_from, token_to) = direction.get_tokens();
+
+    pool.transfer(&env, token_from, &sender, &current_contract, amount)?;
+
+    let (order_input, order_output) = pool.fill_orders(&env, amount, token_from)?;
+    let mut output = order_output;
+
+    if amount > order_input {
+        output += pool.swap(&env, amount - order_input, direction)?;
+    }
+
+    require!(
+        output >= receive_amount_min,
+        Error::InsufficientReceivedAmount
+    );
+
+    pool.transfer(&env, token_to, &current_contract, &recipient, output)?;
+
+    if order_input > 0 {
+        OrdersFilled {
+            sender,
+            from_token: pool.tokens[token_from].clone(),
+            to_token: pool.tokens[token_to].clone(),
+            from_amount: order_input,
+            to_amount: order_output,
+        }
+        .publish(&env);
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,28 @@
+use shared::{soroban_data::SimpleSorobanData, Error};
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    config::Config,
+    order::{LimitOrder, OrderBook},
+    twamm::{LongTermOrder, Twamm},
+};
+
+pub fn get_pool(env: Env) -> Result<Address, Error> {
+    Ok(Config::get(&env)?.pool)
+}
+
+pub fn get_order(env: Env, id: u64) -> Result<LimitOrder, Error> {
+    LimitOrder::get(&env, id)
+}
+
+pub fn get_order_book(env: Env) -> OrderBook {
+    OrderBook::get_or_default(&env)
+}
+
+pub fn get_long_term_order(env: Env, id: u64) -> Result<LongTermOrder, Error> {
+    LongTermOrder::get(&env, id)
+}
+
+pub fn get_twamm(env: Env) -> Twamm {
+    Twamm::get_or_default(&env)
+}
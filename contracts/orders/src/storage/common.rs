@@ -0,0 +1,57 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Swap direction of the pool
+#[contracttype]
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    A2B,
+    B2A,
+}
+
+impl Direction {
+    #[inline]
+    pub fn get_tokens(&self) -> (Token, Token) {
+        match self {
+            Direction::A2B => (Token::A, Token::B),
+            Direction::B2A => (Token::B, Token::A),
+        }
+    }
+}
+
+impl From<Token> for Direction {
+    /// Direction selling the token
+    fn from(token: Token) -> Self {
+        match token {
+            Token::A => Direction::A2B,
+            Token::B => Direction::B2A,
+        }
+    }
+}
+
+/// Pool token
+#[contracttype]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(usize)]
+pub enum Token {
+    A = 0,
+    B = 1,
+}
+
+impl Token {
+    pub fn opposite(&self) -> Token {
+        match self {
+            Token::A => Token::B,
+            Token::B => Token::A,
+        }
+    }
+}
+
+/// Where the rate of a pool token comes from, as kept by the pool
+#[contracttype]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RateSource {
+    Fixed,
+    Provider(Address),
+    /// LP of the base pool of a metapool
+    BasePool(Address),
+}
@@ -0,0 +1,10 @@
+use proc_macros::{extend_ttl_info_instance, Instance, SorobanData, SorobanSimpleData, SymbolKey};
+use soroban_sdk::{contracttype, Address};
+
+/// Pool the orders are filled with and sold on
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, SorobanSimpleData, SymbolKey, Instance)]
+#[extend_ttl_info_instance]
+pub struct Config {
+    pub pool: Address,
+}
@@ -0,0 +1,5 @@
+pub mod common;
+pub mod config;
+pub mod double_values;
+pub mod order;
+pub mod twamm;
@@ -0,0 +1,130 @@
+use proc_macros::{
+    extend_ttl_info, extend_ttl_info_instance, Instance, Persistent, SorobanData,
+    SorobanSimpleData, SymbolKey,
+};
+use shared::{
+    consts::DAY_IN_LEDGERS,
+    soroban_data::{SimpleSorobanData, SorobanData},
+    Error,
+};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use super::common::Token;
+
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+enum OrderKey {
+    /// order id => LimitOrder
+    Order(u64),
+    /// (sell token, price_bp) => OrderTick
+    Tick(Token, u32),
+}
+
+/// Resting order selling `sell_token` for the other pool token at `price_bp`,
+/// the rated amount of the other token per rated sold one in basis points,
+/// so rated tokens are priced by their value at the pool rates
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct LimitOrder {
+    pub owner: Address,
+    pub sell_token: Token,
+    pub price_bp: u32,
+    /// Sell token amount not filled yet
+    pub amount: u128,
+    /// Bought token amount not claimed yet
+    pub proceeds: u128,
+}
+
+impl LimitOrder {
+    pub fn get(env: &Env, id: u64) -> Result<LimitOrder, Error> {
+        LimitOrder::get_by_key(env, &OrderKey::Order(id)).map_err(|_| Error::NotFound)
+    }
+
+    pub fn save(&self, env: &Env, id: u64) {
+        self.save_by_key(env, &OrderKey::Order(id));
+    }
+
+    pub fn remove(env: &Env, id: u64) {
+        LimitOrder::remove_by_key(env, &OrderKey::Order(id));
+    }
+}
+
+/// Ids of the resting orders at a price tick, filled first in first out
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct OrderTick(pub Vec<u64>);
+
+impl OrderTick {
+    pub fn get(env: &Env, sell_token: Token, price_bp: u32) -> OrderTick {
+        OrderTick::get_by_key(env, &OrderKey::Tick(sell_token, price_bp))
+            .unwrap_or_else(|_| OrderTick(Vec::new(env)))
+    }
+
+    /// Removes the tick once it has no orders
+    pub fn save(&self, env: &Env, sell_token: Token, price_bp: u32) {
+        let key = OrderKey::Tick(sell_token, price_bp);
+
+        if self.0.is_empty() {
+            OrderTick::remove_by_key(env, &key);
+        } else {
+            self.save_by_key(env, &key);
+        }
+    }
+}
+
+/// Prices of the ticks with resting orders for each sell token, ascending
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, SorobanSimpleData, SymbolKey, Instance)]
+#[extend_ttl_info_instance]
+pub struct OrderBook {
+    pub next_id: u64,
+    pub ticks_a: Vec<u32>,
+    pub ticks_b: Vec<u32>,
+}
+
+impl OrderBook {
+    /// Prices are kept within 5% around the peg
+    pub const MIN_PRICE_BP: u32 = 9_500;
+    pub const MAX_PRICE_BP: u32 = 10_500;
+
+    /// A new tick beating the worst one evicts it once there are `MAX_TICKS`
+    pub const MAX_TICKS: u32 = 20;
+
+    /// Least improvement on the worst tick price to evict it, so the ticks are not
+    /// replaced one basis point at a time
+    pub const MIN_EVICTION_STEP_BP: u32 = 10;
+
+    pub const MAX_TICK_ORDERS: u32 = 20;
+
+    /// Least order amount in whole tokens of value at the pool rates
+    pub const MIN_ORDER_AMOUNT: u128 = 100;
+
+    /// Orders filled by a single swap at most
+    pub const MAX_FILLS: u32 = 10;
+
+    pub fn get_or_default(env: &Env) -> OrderBook {
+        OrderBook::get(env).unwrap_or_else(|_| OrderBook {
+            next_id: 0,
+            ticks_a: Vec::new(env),
+            ticks_b: Vec::new(env),
+        })
+    }
+
+    pub fn ticks(&self, sell_token: Token) -> &Vec<u32> {
+        match sell_token {
+            Token::A => &self.ticks_a,
+            Token::B => &self.ticks_b,
+        }
+    }
+
+    pub fn ticks_mut(&mut self, sell_token: Token) -> &mut Vec<u32> {
+        match sell_token {
+            Token::A => &mut self.ticks_a,
+            Token::B => &mut self.ticks_b,
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use ethnum::U256;
+use proc_macros::{
+    extend_ttl_info, extend_ttl_info_instance, Instance, Persistent, SorobanData,
+    SorobanSimpleData, SymbolKey,
+};
+use shared::{
+    consts::DAY_IN_LEDGERS,
+    soroban_data::{SimpleSorobanData, SorobanData},
+    utils::safe_cast,
+    Error,
+};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use super::{common::Token, double_values::DoubleU128};
+
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+enum TwammKey {
+    /// order id => LongTermOrder
+    Order(u64),
+    /// expiry timestamp => TwammExpiry
+    Expiry(u64),
+}
+
+/// Order selling `sale_rate` of `sell_token` per second until `expiry`
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct LongTermOrder {
+    pub owner: Address,
+    pub sell_token: Token,
+    pub sale_rate: u128,
+    pub expiry: u64,
+    /// `Twamm::acc_proceeds_p` of the sell token when the proceeds were last withdrawn
+    pub acc_proceeds_p: u128,
+    /// `Twamm::acc_refunds_p` of the sell token when the proceeds were last withdrawn
+    pub acc_refunds_p: u128,
+}
+
+impl LongTermOrder {
+    pub fn get(env: &Env, id: u64) -> Result<LongTermOrder, Error> {
+        LongTermOrder::get_by_key(env, &TwammKey::Order(id)).map_err(|_| Error::NotFound)
+    }
+
+    pub fn save(&self, env: &Env, id: u64) {
+        self.save_by_key(env, &TwammKey::Order(id));
+    }
+
+    pub fn remove(env: &Env, id: u64) {
+        LongTermOrder::remove_by_key(env, &TwammKey::Order(id));
+    }
+
+    /// Proceeds and refunded sales not withdrawn yet and the sell token amount not sold yet
+    /// as of the last execution
+    pub fn get_amounts(&self, env: &Env, twamm: &Twamm) -> Result<(u128, u128, u128), Error> {
+        if self.expiry <= twamm.last_execution {
+            let twamm_expiry = TwammExpiry::get(env, self.expiry);
+
+            return Ok((
+                self.get_share(
+                    twamm_expiry.acc_proceeds_p[self.sell_token],
+                    self.acc_proceeds_p,
+                )?,
+                self.get_share(
+                    twamm_expiry.acc_refunds_p[self.sell_token],
+                    self.acc_refunds_p,
+                )?,
+                0,
+            ));
+        }
+
+        let remaining = self.sale_rate * (self.expiry - twamm.last_execution) as u128;
+
+        Ok((
+            self.get_share(twamm.acc_proceeds_p[self.sell_token], self.acc_proceeds_p)?,
+            self.get_share(twamm.acc_refunds_p[self.sell_token], self.acc_refunds_p)?,
+            remaining,
+        ))
+    }
+
+    /// Share of the order of an accumulator per unit of sale rate since `acc_p_before`
+    #[inline]
+    fn get_share(&self, acc_p: u128, acc_p_before: u128) -> Result<u128, Error> {
+        safe_cast((U256::new(self.sale_rate) * U256::new(acc_p - acc_p_before)) >> Twamm::P)
+            .map_err(|_| Error::PoolOverflow)
+    }
+}
+
+/// Orders ending at an expiry, kept until all of them are withdrawn or cancelled
+#[contracttype]
+#[derive(Debug, Clone, Default, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct TwammExpiry {
+    /// Sale rates per sell token ending at the expiry
+    pub sale_rates: DoubleU128,
+    /// `Twamm::acc_proceeds_p` at the expiry, set once it is executed
+    pub acc_proceeds_p: DoubleU128,
+    /// `Twamm::acc_refunds_p` at the expiry, set once it is executed
+    pub acc_refunds_p: DoubleU128,
+    pub orders: u32,
+}
+
+impl TwammExpiry {
+    pub fn get(env: &Env, expiry: u64) -> TwammExpiry {
+        TwammExpiry::get_by_key(env, &TwammKey::Expiry(expiry)).unwrap_or_default()
+    }
+
+    /// Removes the expiry once it has no orders
+    pub fn save(&self, env: &Env, expiry: u64) {
+        let key = TwammKey::Expiry(expiry);
+
+        if self.orders == 0 {
+            TwammExpiry::remove_by_key(env, &key);
+        } else {
+            self.save_by_key(env, &key);
+        }
+    }
+}
+
+/// Long-term orders state, the sum of their sale rates per sell token is swapped
+/// on the curve for the time elapsed since `last_execution` at the next interaction
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, SorobanSimpleData, SymbolKey, Instance)]
+#[extend_ttl_info_instance]
+pub struct Twamm {
+    pub next_id: u64,
+    pub last_execution: u64,
+    pub sale_rates: DoubleU128,
+    /// Proceeds per unit of sale rate for each sell token, `Twamm::P` precision
+    pub acc_proceeds_p: DoubleU128,
+    /// Sales skipped as they could not be swapped, per unit of sale rate for each sell token,
+    /// `Twamm::P` precision. They are returned to the orders with the proceeds
+    pub acc_refunds_p: DoubleU128,
+    /// Pending expiries with orders, ascending
+    pub expiries: Vec<u64>,
+}
+
+impl Twamm {
+    /// Bits of the fixed point accumulators
+    pub const P: u128 = 48;
+
+    /// Orders expire at the end of a day, so there are at most `MAX_INTERVALS` pending expiries
+    pub const INTERVAL: u64 = 24 * 60 * 60;
+
+    pub const MAX_INTERVALS: u32 = 30;
+
+    pub fn get_or_default(env: &Env) -> Twamm {
+        Twamm::get(env).unwrap_or_else(|_| Twamm {
+            next_id: 0,
+            last_execution: env.ledger().timestamp(),
+            sale_rates: DoubleU128::default(),
+            acc_proceeds_p: DoubleU128::default(),
+            acc_refunds_p: DoubleU128::default(),
+            expiries: Vec::new(env),
+        })
+    }
+}
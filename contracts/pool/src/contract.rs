@@ -1,21 +1,31 @@
 use shared::{utils::extend_ttl_instance, Error};
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
 
 use crate::{
     methods::{
-        internal::pool_view::WithdrawAmountView,
+        internal::pool_view::{SwapQuote, WithdrawAmountView},
         public::{
-            claim_admin_fee, claim_rewards, deposit, initialize, set_admin, set_admin_fee_share,
-            set_fee_share, swap, withdraw,
+            add_reward_token, cancel_intent, claim_admin_fee, claim_admin_fee_to, claim_all,
+            claim_base_rewards, claim_protocol_fee, claim_rewards, claim_rewards_for,
+            compound_rewards, deposit, deposit_for, execute_signed_swap, initialize,
+            initialize_metapool, lock, multicall, notify_reward_amount, ramp_a, set_admin,
+            set_admin_fee_share, set_admin_fee_share_by_factory, set_auto_compound, set_fee_share,
+            set_fee_share_by_factory, set_intent_signer, set_operator, set_paused,
+            set_protocol_fee_share, set_rate_providers, stop_ramp_a, swap, swap_underlying,
+            transfer_lp, unlock, withdraw, withdraw_to,
         },
         view::{
-            get_d, get_deposit_amount, get_pool, get_receive_amount, get_send_amount,
-            get_user_deposit, get_withdraw_amount, pending_reward,
+            get_d, get_deposit_amount, get_pool, get_receive_amount, get_reward_schedule,
+            get_reward_tokens, get_send_amount, get_user_deposit, get_virtual_price,
+            get_withdraw_amount, pending_reward, pending_reward_token, pending_reward_tokens,
+            quote_curve_swap, quote_curve_swap_exact_out,
         },
     },
     storage::{
-        common::{Direction, Token},
+        common::{Direction, PoolOp, Token},
+        intent::SwapIntent,
         pool::Pool,
+        reward::RewardSchedule,
         user_deposit::UserDeposit,
     },
 };
@@ -34,6 +44,9 @@ impl PoolContract {
         token_b: Address,
         fee_share_bp: u128,
         admin_fee_share_bp: u128,
+        factory: Address,
+        system_precision: u32,
+        max_token_balance: u128,
     ) -> Result<(), Error> {
         initialize(
             env,
@@ -43,26 +56,86 @@ impl PoolContract {
             token_b,
             fee_share_bp,
             admin_fee_share_bp,
+            factory,
+            system_precision,
+            max_token_balance,
         )
     }
 
+    /// Pool of `token` against the LP of `base_pool`
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_metapool(
+        env: Env,
+        admin: Address,
+        a: u128,
+        token: Address,
+        base_pool: Address,
+        fee_share_bp: u128,
+        admin_fee_share_bp: u128,
+        factory: Address,
+        system_precision: u32,
+        max_token_balance: u128,
+    ) -> Result<(), Error> {
+        initialize_metapool(
+            env,
+            admin,
+            a,
+            token,
+            base_pool,
+            fee_share_bp,
+            admin_fee_share_bp,
+            factory,
+            system_precision,
+            max_token_balance,
+        )
+    }
+
+    /// Returns the LP minted for the amounts, without the auto-compounded rewards
     pub fn deposit(
         env: Env,
         sender: Address,
         amounts: (u128, u128),
         min_lp_amount: u128,
-    ) -> Result<(), Error> {
+    ) -> Result<u128, Error> {
         extend_ttl_instance(&env);
 
         deposit(env, sender, amounts, min_lp_amount)
     }
 
+    /// Deposits the tokens of `sender` and credits the LP to `beneficiary`,
+    /// returns the minted LP like `deposit`
+    pub fn deposit_for(
+        env: Env,
+        sender: Address,
+        beneficiary: Address,
+        amounts: (u128, u128),
+        min_lp_amount: u128,
+    ) -> Result<u128, Error> {
+        extend_ttl_instance(&env);
+
+        deposit_for(env, sender, beneficiary, amounts, min_lp_amount)
+    }
+
     pub fn withdraw(env: Env, sender: Address, lp_amount: u128) -> Result<(), Error> {
         extend_ttl_instance(&env);
 
         withdraw(env, sender, lp_amount)
     }
 
+    /// Withdraws the LP of `sender` to `recipient`, `min_amounts` are the least token amounts
+    /// received without the rewards
+    pub fn withdraw_to(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        lp_amount: u128,
+        min_amounts: (u128, u128),
+    ) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        withdraw_to(env, sender, recipient, lp_amount, min_amounts)
+    }
+
     pub fn swap(
         env: Env,
         sender: Address,
@@ -83,12 +156,139 @@ impl PoolContract {
         )
     }
 
+    /// Executes the operations in order for the sender and saves the pool once,
+    /// fails as a whole if any operation fails. Returns the received amount of every swap
+    /// and 0 for the other operations
+    pub fn multicall(env: Env, sender: Address, ops: Vec<PoolOp>) -> Result<Vec<u128>, Error> {
+        extend_ttl_instance(&env);
+
+        multicall(env, sender, ops)
+    }
+
+    /// Sets the ed25519 key signing the swap intents of the owner
+    pub fn set_intent_signer(
+        env: Env,
+        owner: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_intent_signer(env, owner, public_key)
+    }
+
+    /// Fills `amount_in` of a swap intent signed by the owner key, the intent can be filled
+    /// in parts until its `amount_in`. The owner approves the input tokens to the pool.
+    /// Returns the output amount sent to the owner
+    pub fn execute_signed_swap(
+        env: Env,
+        intent: SwapIntent,
+        signature: BytesN<64>,
+        amount_in: u128,
+    ) -> Result<u128, Error> {
+        extend_ttl_instance(&env);
+
+        execute_signed_swap(env, intent, signature, amount_in)
+    }
+
+    pub fn cancel_intent(env: Env, owner: Address, nonce: u64) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        cancel_intent(env, owner, nonce)
+    }
+
+    /// Metapool only, swaps between the metapool token and a base pool token
+    pub fn swap_underlying(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        amount_in: u128,
+        receive_amount_min: u128,
+        token_from: Address,
+        token_to: Address,
+    ) -> Result<u128, Error> {
+        extend_ttl_instance(&env);
+
+        swap_underlying(
+            env,
+            sender,
+            recipient,
+            amount_in,
+            receive_amount_min,
+            token_from,
+            token_to,
+        )
+    }
+
+    pub fn transfer_lp(env: Env, from: Address, to: Address, lp_amount: u128) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        transfer_lp(env, from, to, lp_amount)
+    }
+
+    /// Locks LP of the sender for 1 to 12 months, locked LP can't be withdrawn or transferred
+    /// and earns rewards with up to 2x weight
+    pub fn lock(env: Env, sender: Address, lp_amount: u128, months: u32) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        lock(env, sender, lp_amount, months)
+    }
+
+    /// Removes the expired lock of the user and its reward boost
+    pub fn unlock(env: Env, user: Address) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        unlock(env, user)
+    }
+
     pub fn claim_rewards(env: Env, sender: Address) -> Result<(), Error> {
         extend_ttl_instance(&env);
 
         claim_rewards(env, sender)
     }
 
+    /// Claims the rewards of `owner` to `recipient`, callable by the operator set by the owner
+    pub fn claim_rewards_for(env: Env, owner: Address, recipient: Address) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        claim_rewards_for(env, owner, recipient)
+    }
+
+    /// Sets the operator allowed to claim the sender rewards, `None` removes it
+    pub fn set_operator(env: Env, sender: Address, operator: Option<Address>) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_operator(env, sender, operator)
+    }
+
+    /// Deposits the pending rewards of the sender back as liquidity, returns the minted LP
+    pub fn compound_rewards(env: Env, sender: Address, min_lp_amount: u128) -> Result<u128, Error> {
+        extend_ttl_instance(&env);
+
+        compound_rewards(env, sender, min_lp_amount)
+    }
+
+    /// When enabled the rewards settled on deposit and withdraw are deposited back as liquidity
+    pub fn set_auto_compound(env: Env, sender: Address, enabled: bool) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_auto_compound(env, sender, enabled)
+    }
+
+    /// Claims the pool token rewards and the reward tokens,
+    /// returns (token, amount) with the pool tokens first
+    pub fn claim_all(env: Env, sender: Address) -> Result<Vec<(Address, u128)>, Error> {
+        extend_ttl_instance(&env);
+
+        claim_all(env, sender)
+    }
+
+    /// Transfers the accrued protocol fee to `recipient`, callable by the factory only
+    pub fn claim_protocol_fee(env: Env, recipient: Address) -> Result<(u128, u128), Error> {
+        extend_ttl_instance(&env);
+
+        claim_protocol_fee(env, recipient)
+    }
+
     // -------- Admin --------
 
     pub fn claim_admin_fee(env: Env) -> Result<(), Error> {
@@ -97,6 +297,48 @@ impl PoolContract {
         claim_admin_fee(env)
     }
 
+    /// Partial admin fee claim to `recipient`, optionally converted into one token
+    /// by a swap through the pool. Returns the sent amounts
+    pub fn claim_admin_fee_to(
+        env: Env,
+        recipient: Address,
+        amounts: (u128, u128),
+        convert_to: Option<Token>,
+        receive_amount_min: u128,
+    ) -> Result<(u128, u128), Error> {
+        extend_ttl_instance(&env);
+
+        claim_admin_fee_to(env, recipient, amounts, convert_to, receive_amount_min)
+    }
+
+    /// Registers a reward token streamed to the LP, at most 5
+    pub fn add_reward_token(env: Env, token: Address) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        add_reward_token(env, token)
+    }
+
+    /// Transfers `amount` of the reward token from the admin and streams it,
+    /// with the rest of the current period, over `duration` seconds
+    pub fn notify_reward_amount(
+        env: Env,
+        token: Address,
+        amount: u128,
+        duration: u64,
+    ) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        notify_reward_amount(env, token, amount, duration)
+    }
+
+    /// Metapool only, returns the amounts of the base pool tokens claimed and deposited back,
+    /// the base LP is credited to the metapool LPs
+    pub fn claim_base_rewards(env: Env) -> Result<(u128, u128), Error> {
+        extend_ttl_instance(&env);
+
+        claim_base_rewards(env)
+    }
+
     pub fn set_admin(env: Env, new_admin: Address) -> Result<(), Error> {
         extend_ttl_instance(&env);
 
@@ -115,6 +357,66 @@ impl PoolContract {
         set_fee_share(env, fee_share_bp)
     }
 
+    /// `set_fee_share` of a pool managed by the factory, callable by the factory only
+    pub fn set_fee_share_by_factory(env: Env, fee_share_bp: u128) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_fee_share_by_factory(env, fee_share_bp)
+    }
+
+    /// `set_admin_fee_share` of a pool managed by the factory, callable by the factory only
+    pub fn set_admin_fee_share_by_factory(env: Env, admin_fee_share_bp: u128) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_admin_fee_share_by_factory(env, admin_fee_share_bp)
+    }
+
+    /// Moves A linearly to `future_a` until `future_time`, callable by the factory only.
+    /// The ramp lasts at least a day and changes A by 10x at most
+    pub fn ramp_a(env: Env, future_a: u128, future_time: u64) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        ramp_a(env, future_a, future_time)
+    }
+
+    /// Stops the A ramp at the current A, callable by the factory only
+    pub fn stop_ramp_a(env: Env) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        stop_ramp_a(env)
+    }
+
+    /// Disables swaps and deposits, callable by the factory only
+    pub fn pause(env: Env) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_paused(env, true)
+    }
+
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_paused(env, false)
+    }
+
+    /// Callable by the factory only
+    pub fn set_protocol_fee_share(env: Env, share_bp: u128) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_protocol_fee_share(env, share_bp)
+    }
+
+    /// Only before the first deposit, rates are fetched right away
+    pub fn set_rate_providers(
+        env: Env,
+        rate_providers: (Option<Address>, Option<Address>),
+        rate_max_age: u64,
+    ) -> Result<(), Error> {
+        extend_ttl_instance(&env);
+
+        set_rate_providers(env, rate_providers, rate_max_age)
+    }
+
     // -------- View --------
 
     pub fn pending_reward(env: Env, user: Address) -> Result<(u128, u128), Error> {
@@ -125,14 +427,39 @@ impl PoolContract {
         get_pool(env)
     }
 
+    pub fn get_reward_tokens(env: Env) -> Result<Vec<Address>, Error> {
+        get_reward_tokens(env)
+    }
+
+    pub fn get_reward_schedule(env: Env, token: Address) -> Result<RewardSchedule, Error> {
+        get_reward_schedule(env, token)
+    }
+
+    /// Accrued amount of the reward token
+    pub fn pending_reward_token(env: Env, user: Address, token: Address) -> Result<u128, Error> {
+        pending_reward_token(env, user, token)
+    }
+
+    /// Accrued amounts of all reward tokens
+    pub fn pending_reward_tokens(env: Env, user: Address) -> Result<Vec<(Address, u128)>, Error> {
+        pending_reward_tokens(env, user)
+    }
+
     pub fn get_user_deposit(env: Env, user: Address) -> Result<UserDeposit, Error> {
         get_user_deposit(env, user)
     }
 
+    /// Invariant D of the current balances (system precision)
     pub fn get_d(env: Env) -> Result<u128, Error> {
         get_d(env)
     }
 
+    /// Value of one LP in the pool tokens (D / total LP), with 18 decimals.
+    /// 1e18 for an empty pool
+    pub fn get_virtual_price(env: Env) -> Result<u128, Error> {
+        get_virtual_price(env)
+    }
+
     pub fn get_receive_amount(
         env: Env,
         input: u128,
@@ -145,6 +472,27 @@ impl PoolContract {
         get_send_amount(env, output, token_to)
     }
 
+    /// Curve swap of `input` with the price impact, the marginal prices before and after
+    /// and the new balances. Only the curve is quoted, a swap of the orders contract fills
+    /// the resting limit orders first and runs the pending long-term sales, so it can receive more
+    pub fn quote_curve_swap(
+        env: Env,
+        input: u128,
+        direction: Direction,
+    ) -> Result<SwapQuote, Error> {
+        quote_curve_swap(env, input, direction)
+    }
+
+    /// Curve swap quote of the input receiving exactly `output`, without the limit orders
+    /// and the long-term sales
+    pub fn quote_curve_swap_exact_out(
+        env: Env,
+        output: u128,
+        direction: Direction,
+    ) -> Result<SwapQuote, Error> {
+        quote_curve_swap_exact_out(env, output, direction)
+    }
+
     pub fn get_withdraw_amount(env: Env, lp_amount: u128) -> Result<WithdrawAmountView, Error> {
         get_withdraw_amount(env, lp_amount)
     }
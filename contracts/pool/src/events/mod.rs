@@ -18,6 +18,8 @@ pub struct Swapped {
 #[contracttype]
 pub struct Deposit {
     pub user: Address,
+    /// Credited with the LP
+    pub beneficiary: Address,
     pub lp_amount: u128,
     pub amounts: (u128, u128),
 }
@@ -26,6 +28,8 @@ pub struct Deposit {
 #[contracttype]
 pub struct Withdraw {
     pub user: Address,
+    /// Received the tokens
+    pub recipient: Address,
     pub lp_amount: u128,
     pub amounts: (u128, u128),
     pub fees: (u128, u128),
@@ -34,6 +38,88 @@ pub struct Withdraw {
 #[derive(Event)]
 #[contracttype]
 pub struct RewardsClaimed {
+    /// Owner of the LP
     pub user: Address,
+    pub recipient: Address,
     pub rewards: (u128, u128),
 }
+
+#[derive(Event)]
+#[contracttype]
+pub struct OperatorSet {
+    pub user: Address,
+    pub operator: Address,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct OperatorRemoved {
+    pub user: Address,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct RewardsCompounded {
+    pub user: Address,
+    pub rewards: (u128, u128),
+    pub lp_amount: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct LpTransferred {
+    pub from: Address,
+    pub to: Address,
+    pub lp_amount: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct RewardNotified {
+    pub token: Address,
+    pub amount: u128,
+    pub rate: u128,
+    pub period_finish: u64,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct RewardTokenClaimed {
+    pub user: Address,
+    pub token: Address,
+    pub amount: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct Locked {
+    pub user: Address,
+    pub lp_amount: u128,
+    pub unlock_time: u64,
+    pub weight_bp: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct Unlocked {
+    pub user: Address,
+    pub lp_amount: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct IntentFilled {
+    pub owner: Address,
+    pub nonce: u64,
+    pub amount_in: u128,
+    pub amount_out: u128,
+    /// Input amount filled of the intent so far
+    pub filled: u128,
+}
+
+#[derive(Event)]
+#[contracttype]
+pub struct IntentCancelled {
+    pub owner: Address,
+    pub nonce: u64,
+}
@@ -0,0 +1,80 @@
+use shared::{utils::safe_cast, Error};
+use soroban_sdk::{token, Address, Env, Vec};
+
+use crate::storage::{
+    pool::Pool,
+    reward::{RewardSchedule, RewardTokens, UserReward},
+};
+
+impl Pool {
+    /// Accrues the reward tokens of the user shares before they change from `shares_before`
+    /// to `shares_after`, the total reward shares must not be changed yet
+    pub(crate) fn checkpoint_rewards(
+        &self,
+        env: &Env,
+        user: &Address,
+        shares_before: u128,
+        shares_after: u128,
+    ) -> Result<(), Error> {
+        let now = env.ledger().timestamp();
+
+        for token in RewardTokens::get_or_default(env) {
+            let mut schedule = RewardSchedule::get(env, &token)?;
+            schedule.update(now, self.total_reward_shares)?;
+
+            let mut user_reward = UserReward::get(env, user, &token);
+            user_reward.unclaimed = user_reward.get_pending(&schedule, shares_before)?;
+            user_reward.reward_debt = schedule.get_reward_debt(shares_after)?;
+
+            schedule.save(env, &token);
+            user_reward.save(env, user, &token);
+        }
+
+        Ok(())
+    }
+
+    /// Sends all accrued reward tokens to the user, returns the claimed amounts
+    pub(crate) fn claim_reward_tokens(
+        &self,
+        env: &Env,
+        user: &Address,
+        shares: u128,
+    ) -> Result<Vec<(Address, u128)>, Error> {
+        self.checkpoint_rewards(env, user, shares, shares)?;
+
+        let mut claimed = Vec::new(env);
+
+        for token in RewardTokens::get_or_default(env) {
+            let mut user_reward = UserReward::get(env, user, &token);
+            let amount = user_reward.unclaimed;
+
+            if amount > 0 {
+                user_reward.unclaimed = 0;
+                user_reward.save(env, user, &token);
+
+                token::Client::new(env, &token).transfer(
+                    &env.current_contract_address(),
+                    user,
+                    &safe_cast(amount)?,
+                );
+            }
+
+            claimed.push_back((token, amount));
+        }
+
+        Ok(claimed)
+    }
+
+    pub fn get_pending_reward_token(
+        &self,
+        env: &Env,
+        user: &Address,
+        shares: u128,
+        token: &Address,
+    ) -> Result<u128, Error> {
+        let mut schedule = RewardSchedule::get(env, token)?;
+        schedule.update(env.ledger().timestamp(), self.total_reward_shares)?;
+
+        UserReward::get(env, user, token).get_pending(&schedule, shares)
+    }
+}
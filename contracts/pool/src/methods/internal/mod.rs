@@ -1,2 +1,5 @@
+pub mod gauge;
 pub mod pool;
 pub mod pool_view;
+pub mod ramp;
+pub mod rates;
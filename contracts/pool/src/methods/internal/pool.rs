@@ -15,16 +15,32 @@ use crate::storage::{
     user_deposit::UserDeposit,
 };
 
-use super::pool_view::WithdrawAmount;
+use super::pool_view::{DepositAmount, ReceiveAmount, WithdrawAmount};
 
 impl Pool {
     pub const BP: u128 = 10000;
 
-    pub(crate) const MAX_TOKEN_BALANCE: u128 = 2u128.pow(40);
-    pub(crate) const SYSTEM_PRECISION: u32 = 3;
-
     pub const P: u128 = 48;
 
+    /// Cap on the protocol fee share the factory can set
+    pub const MAX_PROTOCOL_FEE_SHARE_BP: u128 = 5000;
+
+    /// LP can be locked for 1 to `MAX_LOCK_MONTHS` months of `LOCK_MONTH` seconds
+    pub const LOCK_MONTH: u64 = 30 * 24 * 60 * 60;
+
+    pub const MAX_LOCK_MONTHS: u32 = 12;
+
+    /// LP amount of the first deposit burned, one LP unit in the pool system precision,
+    /// so the LP supply never drops below it. It is not owned by any deposit and earns no rewards
+    pub fn min_locked_lp(&self) -> u128 {
+        10u128.pow(self.system_precision)
+    }
+
+    /// Reward weight of LP locked for `months`, from 1x up to 2x for `MAX_LOCK_MONTHS`
+    pub fn lock_weight_bp(months: u32) -> u128 {
+        Pool::BP + Pool::BP * months as u128 / Pool::MAX_LOCK_MONTHS as u128
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn swap(
         &mut self,
@@ -35,114 +51,155 @@ impl Pool {
         receive_amount_min: u128,
         direction: Direction,
     ) -> Result<(u128, u128), Error> {
+        require!(!self.paused, Error::Paused);
+
         if amount == 0 {
             return Ok((0, 0));
         }
 
         let current_contract = env.current_contract_address();
         let (token_from, token_to) = direction.get_tokens();
-        let receive_amount = self.get_receive_amount(amount, token_from)?;
 
-        self.get_token(env, token_from)
-            .transfer(&sender, &current_contract, &safe_cast(amount)?);
+        self.transfer(env, token_from as usize, &sender, &current_contract, amount)?;
 
-        self.token_balances[token_from] = receive_amount.token_from_new_balance;
-        self.token_balances[token_to] = receive_amount.token_to_new_balance;
-
-        self.add_rewards(receive_amount.fee, token_to);
+        let receive_amount = self.apply_swap(amount, token_from)?;
 
         require!(
             receive_amount.output >= receive_amount_min,
             Error::InsufficientReceivedAmount
         );
 
-        self.get_token(env, token_to).transfer(
+        self.transfer(
+            env,
+            token_to as usize,
             &current_contract,
             &recipient,
-            &safe_cast(receive_amount.output)?,
-        );
+            receive_amount.output,
+        )?;
 
         Ok((receive_amount.output, receive_amount.fee))
     }
 
+    /// Moves the balances by the swap of `amount` already held by the pool,
+    /// the fee and the dust are credited to rewards
+    pub(crate) fn apply_swap(
+        &mut self,
+        amount: u128,
+        token_from: Token,
+    ) -> Result<ReceiveAmount, Error> {
+        let token_to = token_from.opposite();
+        let receive_amount = self.get_receive_amount(amount, token_from)?;
+
+        self.token_balances[token_from] = receive_amount.token_from_new_balance;
+        self.token_balances[token_to] = receive_amount.token_to_new_balance;
+
+        self.add_rewards(receive_amount.fee, token_to)?;
+        self.add_rewards(receive_amount.dust, token_from)?;
+
+        Ok(receive_amount)
+    }
+
+    /// Takes the tokens from `sender` and credits the LP to `beneficiary` owning `user_deposit`,
+    /// returns the settled rewards, the deposited LP and the LP compounded from the rewards
     pub fn deposit(
         &mut self,
         env: &Env,
         amounts: DoubleU128,
         sender: Address,
+        beneficiary: Address,
         user_deposit: &mut UserDeposit,
         min_lp_amount: u128,
-    ) -> Result<(DoubleU128, u128), Error> {
+    ) -> Result<(DoubleU128, u128, u128), Error> {
+        require!(!self.paused, Error::Paused);
+
         let current_contract = env.current_contract_address();
 
         if self.total_lp_amount == 0 {
-            require!(amounts.data.0 == amounts.data.1, Error::InvalidFirstDeposit);
+            // Balanced by value
+            require!(
+                self.amount_to_system_precision(amounts[0], 0)?
+                    == self.amount_to_system_precision(amounts[1], 1)?,
+                Error::InvalidFirstDeposit
+            );
         }
 
         let deposit_amount = self.get_deposit_amount(amounts.clone())?;
         self.token_balances = deposit_amount.new_token_balances;
+        self.d = deposit_amount.new_d;
+        let mut lp_amount = deposit_amount.lp_amount;
 
-        require!(deposit_amount.lp_amount >= min_lp_amount, Error::Slippage);
+        if self.total_lp_amount == 0 {
+            let locked_lp = self.min_locked_lp();
+            require!(lp_amount > locked_lp, Error::InvalidFirstDeposit);
+
+            // Burned, the locked LP has no deposit and takes no reward shares
+            self.total_lp_amount += locked_lp;
+            lp_amount -= locked_lp;
+        }
+
+        require!(lp_amount >= min_lp_amount, Error::Slippage);
 
         for (index, amount) in amounts.to_array().into_iter().enumerate() {
             if amount == 0 {
                 continue;
             }
 
-            self.get_token_by_index(env, index).transfer(
-                &sender,
-                &current_contract,
-                &safe_cast(amount)?,
-            );
+            self.transfer(env, index, &sender, &current_contract, amount)?;
         }
 
-        let rewards = self.deposit_lp(user_deposit, deposit_amount.lp_amount)?;
+        let rewards = self.deposit_lp(env, &beneficiary, user_deposit, lp_amount)?;
 
-        for (index, reward) in rewards.to_array().into_iter().enumerate() {
+        for (index, dust) in deposit_amount.dust.to_array().into_iter().enumerate() {
+            self.add_rewards(dust, index.into())?;
+        }
+
+        let (payout, compounded_lp) =
+            self.settle_rewards(env, &beneficiary, user_deposit, rewards.clone(), true)?;
+
+        for (index, reward) in payout.to_array().into_iter().enumerate() {
             if reward == 0 {
                 continue;
             }
 
-            self.get_token_by_index(env, index).transfer(
-                &current_contract,
-                &sender,
-                &safe_cast(reward)?,
-            );
+            self.transfer(env, index, &current_contract, &beneficiary, reward)?;
         }
 
-        Ok((rewards, deposit_amount.lp_amount))
+        Ok((rewards, lp_amount, compounded_lp))
     }
 
+    /// Burns the LP of `sender` and sends the tokens with the rewards to `recipient`,
+    /// returns the settled rewards and the LP compounded from them,
+    /// the rewards are only compounded when `can_compound` and some LP is left
     pub fn withdraw(
         &mut self,
         env: &Env,
         sender: Address,
+        recipient: Address,
         user_deposit: &mut UserDeposit,
         lp_amount: u128,
-    ) -> Result<(WithdrawAmount, DoubleU128), Error> {
+        can_compound: bool,
+    ) -> Result<(WithdrawAmount, DoubleU128, u128), Error> {
         let current_contract = env.current_contract_address();
-        let d0 = self.total_lp_amount;
+        let d0 = self.d;
         let old_balances = self.token_balances.clone();
         let withdraw_amount = self.get_withdraw_amount(lp_amount)?;
-        let rewards_amounts = self.withdraw_lp(user_deposit, lp_amount)?;
+        let rewards_amounts = self.withdraw_lp(env, &sender, user_deposit, lp_amount)?;
+        let compound = can_compound && user_deposit.auto_compound && user_deposit.lp_amount > 0;
 
         for index in withdraw_amount.indexes {
-            let token_amount = self.amount_from_system_precision(
-                withdraw_amount.amounts[index],
-                self.tokens_decimals[index],
-            );
-            let token_amount = token_amount + rewards_amounts[index];
+            let mut token_amount =
+                self.amount_from_system_precision(withdraw_amount.amounts[index], index)?;
+            if !compound {
+                token_amount += rewards_amounts[index];
+            }
 
-            self.add_rewards(withdraw_amount.fees[index], index.into());
-            self.get_token_by_index(env, index).transfer(
-                &current_contract,
-                &sender,
-                &safe_cast(token_amount)?,
-            );
+            self.add_rewards(withdraw_amount.fees[index], index.into())?;
+            self.transfer(env, index, &current_contract, &recipient, token_amount)?;
         }
 
         self.token_balances = withdraw_amount.new_token_balances.clone();
-        let d1 = self.total_lp_amount;
+        self.d = withdraw_amount.new_d;
+        let d1 = self.d;
 
         require!(
             self.token_balances[0] < old_balances[0]
@@ -151,43 +208,138 @@ impl Pool {
             Error::ZeroChanges
         );
 
-        Ok((withdraw_amount, rewards_amounts))
+        let mut compounded_lp = 0;
+
+        if compound {
+            let payout;
+            (payout, compounded_lp) =
+                self.settle_rewards(env, &sender, user_deposit, rewards_amounts.clone(), true)?;
+
+            for (index, reward) in payout.to_array().into_iter().enumerate() {
+                if reward > 0 {
+                    self.transfer(env, index, &current_contract, &recipient, reward)?;
+                }
+            }
+        }
+
+        Ok((withdraw_amount, rewards_amounts, compounded_lp))
+    }
+
+    /// Re-deposits the settled rewards of an auto-compounding user as liquidity,
+    /// returns the rewards left to pay out and the compounded LP
+    pub(crate) fn settle_rewards(
+        &mut self,
+        env: &Env,
+        user: &Address,
+        user_deposit: &mut UserDeposit,
+        rewards: DoubleU128,
+        can_compound: bool,
+    ) -> Result<(DoubleU128, u128), Error> {
+        if !can_compound || !user_deposit.auto_compound || rewards.is_zero() {
+            return Ok((rewards, 0));
+        }
+
+        match self.get_deposit_amount(rewards.clone()) {
+            Ok(deposit_amount) => {
+                let lp_amount = self.compound(env, user, user_deposit, deposit_amount)?;
+                Ok((DoubleU128::default(), lp_amount))
+            }
+            // Rewards below the system precision are paid out
+            Err(Error::ZeroAmount) => Ok((rewards, 0)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Adds the reward tokens already held by the pool to the balances and mints the LP
+    /// of `deposit_amount` to the user, returns the minted LP
+    pub(crate) fn compound(
+        &mut self,
+        env: &Env,
+        user: &Address,
+        user_deposit: &mut UserDeposit,
+        deposit_amount: DepositAmount,
+    ) -> Result<u128, Error> {
+        self.token_balances = deposit_amount.new_token_balances;
+        self.d = deposit_amount.new_d;
+        // Settles the pending rewards, which are the compounded ones
+        self.deposit_lp(env, user, user_deposit, deposit_amount.lp_amount)?;
+
+        for (index, dust) in deposit_amount.dust.to_array().into_iter().enumerate() {
+            self.add_rewards(dust, index.into())?;
+        }
+
+        Ok(deposit_amount.lp_amount)
     }
 
     pub(crate) fn deposit_lp(
         &mut self,
+        env: &Env,
+        user: &Address,
         user_deposit: &mut UserDeposit,
         lp_amount: u128,
     ) -> Result<DoubleU128, Error> {
-        let pending = self.get_pending(user_deposit);
-
-        self.total_lp_amount += lp_amount;
-        user_deposit.lp_amount += lp_amount;
-        user_deposit.reward_debts = self.get_reward_debts(user_deposit);
-
-        Ok(pending)
+        self.update_user_deposit(env, user, user_deposit, |user_deposit| {
+            user_deposit.lp_amount += lp_amount;
+        })
     }
 
     pub(crate) fn withdraw_lp(
         &mut self,
+        env: &Env,
+        user: &Address,
         user_deposit: &mut UserDeposit,
         lp_amount: u128,
     ) -> Result<DoubleU128, Error> {
         require!(user_deposit.lp_amount >= lp_amount, Error::NotEnoughAmount);
 
-        let pending = self.get_pending(user_deposit);
+        let now = env.ledger().timestamp();
+        require!(
+            user_deposit.unlocked_amount(now) >= lp_amount,
+            Error::LpLocked
+        );
+
+        self.update_user_deposit(env, user, user_deposit, |user_deposit| {
+            user_deposit.release_expired_lock(now);
+            user_deposit.lp_amount -= lp_amount;
+        })
+    }
+
+    /// Applies `update` to the user position, the rewards accrued by its old shares are returned
+    /// and the LP and reward share totals follow the change
+    pub(crate) fn update_user_deposit<F>(
+        &mut self,
+        env: &Env,
+        user: &Address,
+        user_deposit: &mut UserDeposit,
+        update: F,
+    ) -> Result<DoubleU128, Error>
+    where
+        F: FnOnce(&mut UserDeposit),
+    {
+        let mut updated_deposit = user_deposit.clone();
+        update(&mut updated_deposit);
+
+        let shares_before = user_deposit.reward_shares();
+        let shares_after = updated_deposit.reward_shares();
+        self.checkpoint_rewards(env, user, shares_before, shares_after)?;
+
+        let pending = self.get_pending(user_deposit)?;
 
-        self.total_lp_amount -= lp_amount;
-        user_deposit.lp_amount -= lp_amount;
-        user_deposit.reward_debts = self.get_reward_debts(user_deposit);
+        self.total_lp_amount =
+            self.total_lp_amount + updated_deposit.lp_amount - user_deposit.lp_amount;
+        self.total_reward_shares = self.total_reward_shares + shares_after - shares_before;
+
+        *user_deposit = updated_deposit;
+        user_deposit.reward_debts = self.get_reward_debts(user_deposit)?;
 
         Ok(pending)
     }
 
+    /// Pays the pending rewards of `user_deposit` to `recipient`
     pub fn claim_rewards(
         &self,
         env: &Env,
-        user: Address,
+        recipient: Address,
         user_deposit: &mut UserDeposit,
     ) -> Result<DoubleU128, Error> {
         let mut pending = DoubleU128::default();
@@ -196,7 +348,7 @@ impl Pool {
             return Ok(pending);
         }
 
-        let rewards = self.get_reward_debts(user_deposit);
+        let rewards = self.get_reward_debts(user_deposit)?;
 
         for (index, reward) in rewards.to_array().into_iter().enumerate() {
             pending[index] = reward - user_deposit.reward_debts[index];
@@ -204,45 +356,72 @@ impl Pool {
             if pending[index] > 0 {
                 user_deposit.reward_debts[index] = reward;
 
-                self.get_token_by_index(env, index).transfer(
+                self.transfer(
+                    env,
+                    index,
                     &env.current_contract_address(),
-                    &user,
-                    &safe_cast(pending[index])?,
-                );
+                    &recipient,
+                    pending[index],
+                )?;
             }
         }
 
         Ok(pending)
     }
 
-    pub(crate) fn add_rewards(&mut self, mut reward_amount: u128, token: Token) {
-        if self.total_lp_amount > 0 {
-            let admin_fee_rewards = reward_amount * self.admin_fee_share_bp / Pool::BP;
-            reward_amount -= admin_fee_rewards;
-            self.acc_rewards_per_share_p[token] +=
-                (reward_amount << Pool::P) / self.total_lp_amount;
-            self.admin_fee_amount[token] += admin_fee_rewards;
+    /// Takes the protocol and admin fees out of `reward_amount` and shares the rest
+    /// by the reward shares, it is kept until there are any
+    pub(crate) fn add_rewards(
+        &mut self,
+        mut reward_amount: u128,
+        token: Token,
+    ) -> Result<(), Error> {
+        let protocol_fee_rewards = reward_amount * self.protocol_fee_share_bp / Pool::BP;
+        reward_amount -= protocol_fee_rewards;
+        let admin_fee_rewards = reward_amount * self.admin_fee_share_bp / Pool::BP;
+        reward_amount -= admin_fee_rewards;
+        self.admin_fee_amount[token] += admin_fee_rewards;
+        self.protocol_fee_amount[token] += protocol_fee_rewards;
+
+        let reward_amount = self.undistributed_rewards[token] + reward_amount;
+
+        match (U256::new(reward_amount) << Pool::P).checked_div(U256::new(self.total_reward_shares))
+        {
+            Some(reward_per_share_p) => {
+                let reward_per_share_p: u128 =
+                    safe_cast(reward_per_share_p).map_err(|_| Error::PoolOverflow)?;
+                self.acc_rewards_per_share_p[token] = self.acc_rewards_per_share_p[token]
+                    .checked_add(reward_per_share_p)
+                    .ok_or(Error::PoolOverflow)?;
+                self.undistributed_rewards[token] = 0;
+            }
+            None => self.undistributed_rewards[token] = reward_amount,
         }
+
+        Ok(())
     }
 
-    pub fn get_pending(&self, user_deposit: &UserDeposit) -> DoubleU128 {
+    pub fn get_pending(&self, user_deposit: &UserDeposit) -> Result<DoubleU128, Error> {
         if user_deposit.lp_amount == 0 {
-            return DoubleU128::default();
+            return Ok(DoubleU128::default());
         }
 
-        DoubleU128::from((
-            ((user_deposit.lp_amount * self.acc_rewards_per_share_p[0]) >> Pool::P)
-                - user_deposit.reward_debts[0],
-            ((user_deposit.lp_amount * self.acc_rewards_per_share_p[1]) >> Pool::P)
-                - user_deposit.reward_debts[1],
-        ))
+        let reward_debts = self.get_reward_debts(user_deposit)?;
+
+        Ok(DoubleU128::from((
+            reward_debts[0] - user_deposit.reward_debts[0],
+            reward_debts[1] - user_deposit.reward_debts[1],
+        )))
     }
 
-    pub fn get_reward_debts(&self, user_deposit: &UserDeposit) -> DoubleU128 {
-        DoubleU128::from((
-            (user_deposit.lp_amount * self.acc_rewards_per_share_p[0]) >> Pool::P,
-            (user_deposit.lp_amount * self.acc_rewards_per_share_p[1]) >> Pool::P,
-        ))
+    pub fn get_reward_debts(&self, user_deposit: &UserDeposit) -> Result<DoubleU128, Error> {
+        let shares = U256::new(user_deposit.reward_shares());
+        let reward_debt = |index: usize| {
+            safe_cast((shares * U256::new(self.acc_rewards_per_share_p[index])) >> Pool::P)
+                .map_err(|_| Error::PoolOverflow)
+        };
+
+        Ok(DoubleU128::from((reward_debt(0)?, reward_debt(1)?)))
     }
 
     // y = (sqrt(x(4AD³ + x (4A(D - x) - D )²)) + x (4A(D - x) - D ))/8Ax
@@ -268,6 +447,15 @@ impl Pool {
         self.get_d(self.token_balances[0], self.token_balances[1])
     }
 
+    /// D per LP with `RATE_ONE` precision, `RATE_ONE` for an empty pool
+    pub fn get_virtual_price(&self) -> u128 {
+        if self.total_lp_amount == 0 {
+            return Self::RATE_ONE;
+        }
+
+        self.get_current_d() * Self::RATE_ONE / self.total_lp_amount
+    }
+
     pub fn get_d(&self, x: u128, y: u128) -> u128 {
         let xy: u128 = x * y;
         // Axy(x+y)
@@ -289,19 +477,102 @@ impl Pool {
         d << 1
     }
 
-    pub(crate) fn amount_to_system_precision(&self, amount: u128, decimals: u32) -> u128 {
-        match decimals.cmp(&Self::SYSTEM_PRECISION) {
-            Ordering::Greater => amount / (10u128.pow(decimals - Self::SYSTEM_PRECISION)),
-            Ordering::Less => amount * (10u128.pow(Self::SYSTEM_PRECISION - decimals)),
-            Ordering::Equal => amount,
+    /// Balances within `max_token_balance` must not overflow `get_d` and `get_y`
+    pub fn validate_precision(&self) -> Result<(), Error> {
+        require!(self.max_token_balance > 0, Error::InvalidArg);
+        require!(
+            10u128.checked_pow(self.system_precision).is_some(),
+            Error::InvalidArg
+        );
+
+        for decimals in self.tokens_decimals.to_array() {
+            require!(
+                10u128
+                    .checked_pow(decimals.abs_diff(self.system_precision))
+                    .is_some(),
+                Error::InvalidArg
+            );
         }
+
+        Self::check_overflow_bounds(self.a, self.max_token_balance).ok_or(Error::InvalidArg)
+    }
+
+    // Worst cases of the intermediate values of `get_d` and `get_y` for x, y, D up to `max_balance`
+    pub(crate) fn check_overflow_bounds(a: u128, max_balance: u128) -> Option<()> {
+        let fits = |value: U256, max: u128| (value <= U256::new(max)).then_some(value);
+
+        let m = U256::new(max_balance);
+        let a4 = U256::new(a).checked_mul(U256::new(4))?;
+
+        // get_d: x + y < m, so xy <= m²/4
+        let xy = fits(m.checked_mul(m)? / 4, u128::MAX)?;
+        let p1 = fits(U256::new(a).checked_mul(m)?.checked_mul(xy)?, u128::MAX)?;
+        let p2 = fits(xy.checked_mul(a4)? / 3, u128::MAX)?;
+        p1.checked_mul(p1)?
+            .checked_add(p2.checked_mul(p2)?.checked_mul(p2)?)?;
+
+        // get_y: x, D <= m, so |4A(D - x) - D| <= 4Am
+        fits(m.checked_mul(m)?, u128::MAX)?;
+        let part1 = a4.checked_mul(m)?;
+        let part1_squared = fits(part1.checked_mul(part1)?, i128::MAX as u128)?;
+        a4.checked_mul(m)?
+            .checked_mul(m)?
+            .checked_mul(m)?
+            .checked_add(part1_squared.checked_mul(m)?)?
+            .checked_mul(m)?;
+        fits(part1.checked_mul(m)?, i128::MAX as u128)?;
+        fits(
+            U256::new(a).checked_mul(U256::new(8))?.checked_mul(m)?,
+            u128::MAX,
+        )?;
+
+        Some(())
     }
 
-    pub(crate) fn amount_from_system_precision(&self, amount: u128, decimals: u32) -> u128 {
-        match decimals.cmp(&Self::SYSTEM_PRECISION) {
-            Ordering::Greater => amount * (10u128.pow(decimals - Self::SYSTEM_PRECISION)),
-            Ordering::Less => amount / (10u128.pow(Self::SYSTEM_PRECISION - decimals)),
+    /// Token amount to the rated system precision amount
+    pub(crate) fn amount_to_system_precision(
+        &self,
+        amount: u128,
+        index: usize,
+    ) -> Result<u128, Error> {
+        let decimals = self.tokens_decimals[index];
+        let amount = match decimals.cmp(&self.system_precision) {
+            Ordering::Greater => amount / (10u128.pow(decimals - self.system_precision)),
+            Ordering::Less => amount
+                .checked_mul(10u128.pow(self.system_precision - decimals))
+                .ok_or(Error::PoolOverflow)?,
             Ordering::Equal => amount,
+        };
+
+        safe_cast(U256::new(amount) * U256::new(self.rates[index]) / U256::new(Self::RATE_ONE))
+            .map_err(|_| Error::PoolOverflow)
+    }
+
+    /// Rated system precision amount to the token amount
+    pub(crate) fn amount_from_system_precision(
+        &self,
+        amount: u128,
+        index: usize,
+    ) -> Result<u128, Error> {
+        let decimals = self.tokens_decimals[index];
+        let amount: u128 =
+            safe_cast(U256::new(amount) * U256::new(Self::RATE_ONE) / U256::new(self.rates[index]))
+                .map_err(|_| Error::PoolOverflow)?;
+
+        match decimals.cmp(&self.system_precision) {
+            Ordering::Greater => amount
+                .checked_mul(10u128.pow(decimals - self.system_precision))
+                .ok_or(Error::PoolOverflow),
+            Ordering::Less => Ok(amount / (10u128.pow(self.system_precision - decimals))),
+            Ordering::Equal => Ok(amount),
         }
     }
+
+    /// Part of `amount` (token precision) truncated by `amount_to_system_precision`,
+    /// credited to the pool as rewards instead of staying in it unaccounted
+    pub(crate) fn system_precision_dust(&self, amount: u128, index: usize) -> Result<u128, Error> {
+        let amount_sp = self.amount_to_system_precision(amount, index)?;
+
+        Ok(amount - self.amount_from_system_precision(amount_sp, index)?)
+    }
 }
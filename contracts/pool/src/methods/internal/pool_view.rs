@@ -1,13 +1,27 @@
-use shared::{require, Error};
+use ethnum::U256;
+use shared::{require, utils::safe_cast, Error};
 use soroban_sdk::contracttype;
 
-use crate::storage::{common::Token, double_values::DoubleU128, pool::Pool};
+use crate::storage::{
+    common::{Direction, Token},
+    double_values::DoubleU128,
+    pool::Pool,
+};
 
 pub struct ReceiveAmount {
     pub token_from_new_balance: u128,
     pub token_to_new_balance: u128,
     pub output: u128,
     pub fee: u128,
+    /// Part of the input truncated by the system precision
+    pub dust: u128,
+}
+
+pub struct SendAmount {
+    pub token_from_new_balance: u128,
+    pub token_to_new_balance: u128,
+    pub input: u128,
+    pub fee: u128,
 }
 
 pub struct WithdrawAmount {
@@ -15,6 +29,7 @@ pub struct WithdrawAmount {
     pub amounts: DoubleU128,
     pub fees: DoubleU128,
     pub new_token_balances: DoubleU128,
+    pub new_d: u128,
 }
 
 #[contracttype]
@@ -35,9 +50,33 @@ impl From<WithdrawAmount> for WithdrawAmountView {
     }
 }
 
+/// Swap on the curve without the resting orders. Prices are amounts of the output token
+/// per input token in whole tokens with `Pool::RATE_ONE` precision
+#[contracttype]
+#[derive(Debug)]
+pub struct SwapQuote {
+    /// token precision
+    pub input: u128,
+    /// token precision
+    pub output: u128,
+    /// token precision
+    pub fee: u128,
+    /// system precision
+    pub new_balances: (u128, u128),
+    pub price_before: u128,
+    pub price_after: u128,
+    /// Output per input, after the fee
+    pub effective_rate: u128,
+    /// Drop of the rate before the fee from `price_before`, in basis points
+    pub price_impact_bp: u128,
+}
+
 pub struct DepositAmount {
     pub lp_amount: u128,
     pub new_token_balances: DoubleU128,
+    pub new_d: u128,
+    /// Parts of the amounts truncated by the system precision
+    pub dust: DoubleU128,
 }
 
 impl Pool {
@@ -47,8 +86,9 @@ impl Pool {
         token_from: Token,
     ) -> Result<ReceiveAmount, Error> {
         let token_to = token_from.opposite();
-        let d0 = self.total_lp_amount;
-        let input_sp = self.amount_to_system_precision(input, self.tokens_decimals[token_from]);
+        let d0 = self.d;
+        let input_sp = self.amount_to_system_precision(input, token_from as usize)?;
+        let dust = self.system_precision_dust(input, token_from as usize)?;
         let mut output = 0;
 
         let token_from_new_balance = self.token_balances[token_from] + input_sp;
@@ -57,8 +97,8 @@ impl Pool {
         if self.token_balances[token_to] > token_to_new_balance {
             output = self.amount_from_system_precision(
                 self.token_balances[token_to] - token_to_new_balance,
-                self.tokens_decimals[token_to],
-            );
+                token_to as usize,
+            )?;
         }
         let fee = output * self.fee_share_bp / Self::BP;
 
@@ -69,60 +109,195 @@ impl Pool {
             token_to_new_balance,
             output,
             fee,
+            dust,
         })
     }
 
     pub fn get_send_amount(&self, output: u128, token_to: Token) -> Result<(u128, u128), Error> {
+        let send_amount = self.calc_send_amount(output, token_to)?;
+
+        Ok((send_amount.input, send_amount.fee))
+    }
+
+    /// Input sending `output` with the balances after the swap
+    pub fn calc_send_amount(&self, output: u128, token_to: Token) -> Result<SendAmount, Error> {
         let token_from = token_to.opposite();
-        let d0 = self.total_lp_amount;
+        let d0 = self.d;
         let fee = output * self.fee_share_bp / (Self::BP - self.fee_share_bp);
         let output_with_fee = output + fee;
-        let output_sp =
-            self.amount_to_system_precision(output_with_fee, self.tokens_decimals[token_to]);
+        let output_sp = self.amount_to_system_precision(output_with_fee, token_to as usize)?;
         let mut input = 0;
 
-        let token_to_new_balance = self.token_balances[token_to] - output_sp;
+        // The curve has no solution once a balance is drained
+        let token_to_new_balance = self.token_balances[token_to]
+            .checked_sub(output_sp)
+            .ok_or(Error::InsufficientReceivedAmount)?;
+        require!(token_to_new_balance > 0, Error::InsufficientReceivedAmount);
 
         let token_from_new_amount = self.get_y(token_to_new_balance, d0)?;
         if self.token_balances[token_from] < token_from_new_amount {
             input = self.amount_from_system_precision(
                 token_from_new_amount - self.token_balances[token_from],
-                self.tokens_decimals[token_from],
-            );
+                token_from as usize,
+            )?;
         }
 
-        Ok((input, fee))
+        Ok(SendAmount {
+            token_from_new_balance: self.token_balances[token_from].max(token_from_new_amount),
+            token_to_new_balance,
+            input,
+            fee,
+        })
+    }
+
+    /// Quote of `input` on the curve alone
+    pub fn quote_curve_swap(&self, input: u128, direction: Direction) -> Result<SwapQuote, Error> {
+        let (token_from, token_to) = direction.get_tokens();
+        // Fails on an empty pool before the curve is solved
+        let price_before = self.get_marginal_price(&self.token_balances, token_from)?;
+        let receive_amount = self.get_receive_amount(input, token_from)?;
+
+        let mut new_balances = DoubleU128::default();
+        new_balances[token_from] = receive_amount.token_from_new_balance;
+        new_balances[token_to] = receive_amount.token_to_new_balance;
+
+        self.get_swap_quote(
+            input,
+            receive_amount.output,
+            receive_amount.fee,
+            new_balances,
+            token_from,
+            price_before,
+        )
+    }
+
+    /// Quote of the input sending exactly `output` on the curve alone
+    pub fn quote_curve_swap_exact_out(
+        &self,
+        output: u128,
+        direction: Direction,
+    ) -> Result<SwapQuote, Error> {
+        let (token_from, token_to) = direction.get_tokens();
+        let price_before = self.get_marginal_price(&self.token_balances, token_from)?;
+        let send_amount = self.calc_send_amount(output, token_to)?;
+
+        let mut new_balances = DoubleU128::default();
+        new_balances[token_from] = send_amount.token_from_new_balance;
+        new_balances[token_to] = send_amount.token_to_new_balance;
+
+        self.get_swap_quote(
+            send_amount.input,
+            output,
+            send_amount.fee,
+            new_balances,
+            token_from,
+            price_before,
+        )
+    }
+
+    fn get_swap_quote(
+        &self,
+        input: u128,
+        output: u128,
+        fee: u128,
+        new_balances: DoubleU128,
+        token_from: Token,
+        price_before: u128,
+    ) -> Result<SwapQuote, Error> {
+        require!(input > 0, Error::ZeroAmount);
+
+        let token_to = token_from.opposite();
+        let price_after = self.get_marginal_price(&new_balances, token_from)?;
+
+        // Token amounts per input in whole tokens
+        let from_unit = U256::new(10u128.pow(self.tokens_decimals[token_from]));
+        let to_unit = U256::new(10u128.pow(self.tokens_decimals[token_to]));
+        let to_rate = |amount: u128| -> Result<u128, Error> {
+            safe_cast(U256::new(amount) * Self::RATE_ONE * from_unit / (to_unit * input))
+                .map_err(|_| Error::PoolOverflow)
+        };
+
+        let effective_rate = to_rate(output)?;
+        let rate_before_fee = to_rate(output + fee)?;
+        let price_impact_bp = (U256::new(price_before.saturating_sub(rate_before_fee)) * Self::BP)
+            .checked_div(U256::new(price_before))
+            .ok_or(Error::ZeroAmount)?;
+        let price_impact_bp = safe_cast(price_impact_bp).map_err(|_| Error::PoolOverflow)?;
+
+        Ok(SwapQuote {
+            input,
+            output,
+            fee,
+            new_balances: new_balances.data,
+            price_before,
+            price_after,
+            effective_rate,
+            price_impact_bp,
+        })
+    }
+
+    /// Output per input of an infinitesimal swap at `balances`, in whole tokens with
+    /// `RATE_ONE` precision. From 4A(x + y) + D = 4AD + D³/4xy:
+    /// -dy/dx = y(16Ax²y + D³) / x(16Axy² + D³)
+    pub fn get_marginal_price(
+        &self,
+        balances: &DoubleU128,
+        token_from: Token,
+    ) -> Result<u128, Error> {
+        let token_to = token_from.opposite();
+        let x = U256::new(balances[token_from]);
+        let y = U256::new(balances[token_to]);
+        require!(x > 0 && y > 0, Error::ZeroAmount);
+
+        let a16 = U256::new(self.a << 4);
+        let d = U256::new(self.d);
+        let ddd = d * d * d;
+
+        let price_sp = (a16 * x * x * y + ddd) * Self::RATE_ONE / (a16 * x * y * y + ddd) * y / x;
+
+        // Rated system precision to whole tokens
+        let price = (price_sp * self.rates[token_from])
+            .checked_div(U256::new(self.rates[token_to]))
+            .ok_or(Error::ZeroAmount)?;
+
+        safe_cast(price).map_err(|_| Error::PoolOverflow)
+    }
+
+    /// Part of D backing `lp_amount`
+    fn lp_to_d(&self, lp_amount: u128) -> Result<u128, Error> {
+        let d_amount = (U256::new(lp_amount) * self.d)
+            .checked_div(U256::new(self.total_lp_amount))
+            .ok_or(Error::ZeroAmount)?;
+
+        safe_cast(d_amount)
     }
 
     pub fn get_withdraw_amount(&self, lp_amount: u128) -> Result<WithdrawAmount, Error> {
-        let d0 = self.total_lp_amount;
+        let d0 = self.d;
         let mut amounts = DoubleU128::default();
 
-        let d1 = d0 - lp_amount;
+        let d1 = d0 - self.lp_to_d(lp_amount)?;
         let (more, less) = if self.token_balances[0] > self.token_balances[1] {
             (0, 1)
         } else {
             (1, 0)
         };
 
-        let more_token_amount = self.token_balances[more] * lp_amount / d0;
+        let more_token_amount = self.token_balances[more] * lp_amount / self.total_lp_amount;
         let y = self.get_y(self.token_balances[more] - more_token_amount, d1)?;
         let less_token_amount = self.token_balances[less] - y;
 
         let mut new_token_balances = self.token_balances.clone();
         let mut fees = DoubleU128::default();
 
-        for (index, token_amount) in [(more, more_token_amount), (less, less_token_amount)] {
-            let token_amount =
-                self.amount_from_system_precision(token_amount, self.tokens_decimals[index]);
+        for (index, token_amount_sp) in [(more, more_token_amount), (less, less_token_amount)] {
+            let token_amount = self.amount_from_system_precision(token_amount_sp, index)?;
             let fee = token_amount * self.fee_share_bp / Self::BP;
 
-            let token_amount =
-                self.amount_to_system_precision(token_amount - fee, self.tokens_decimals[index]);
-
             fees[index] = fee;
-            amounts[index] = token_amount;
-            new_token_balances[index] -= token_amount;
+            amounts[index] = self.amount_to_system_precision(token_amount - fee, index)?;
+            // The fee leaves the balances as rewards, it is not left to the LP as well
+            new_token_balances[index] -= token_amount_sp;
         }
 
         Ok(WithdrawAmount {
@@ -130,15 +305,21 @@ impl Pool {
             fees,
             amounts,
             new_token_balances,
+            new_d: d1,
         })
     }
 
     pub fn get_deposit_amount(&self, amounts: DoubleU128) -> Result<DepositAmount, Error> {
-        let d0 = self.total_lp_amount;
+        let d0 = self.d;
 
         let amounts_sp = DoubleU128::from((
-            self.amount_to_system_precision(amounts[0], self.tokens_decimals[0]),
-            self.amount_to_system_precision(amounts[1], self.tokens_decimals[1]),
+            self.amount_to_system_precision(amounts[0], 0)?,
+            self.amount_to_system_precision(amounts[1], 1)?,
+        ));
+
+        let dust = DoubleU128::from((
+            self.system_precision_dust(amounts[0], 0)?,
+            self.system_precision_dust(amounts[1], 1)?,
         ));
 
         let total_amount = amounts_sp.sum();
@@ -158,15 +339,22 @@ impl Pool {
 
         require!(d1 > d0, Error::Forbidden);
         require!(
-            new_token_balances.sum() < Self::MAX_TOKEN_BALANCE,
+            new_token_balances.sum() < self.max_token_balance,
             Error::PoolOverflow
         );
 
-        let lp_amount = d1 - d0;
+        // LP is minted by the share of D added
+        let lp_amount = if self.total_lp_amount == 0 {
+            d1
+        } else {
+            safe_cast(U256::new(d1 - d0) * self.total_lp_amount / d0)?
+        };
 
         Ok(DepositAmount {
             lp_amount,
             new_token_balances,
+            new_d: d1,
+            dust,
         })
     }
 }
@@ -190,13 +378,26 @@ mod tests {
         pub fn init(env: Env) {
             let token_a = Address::generate(&env);
             let token_b = Address::generate(&env);
-            Pool::from_init_params(20, token_a, token_b, (7, 7), 100, 1).save(&env);
+            let factory = Address::generate(&env);
+            Pool::from_init_params(
+                20,
+                token_a,
+                token_b,
+                (7, 7),
+                3,
+                2u128.pow(40),
+                100,
+                1,
+                factory,
+            )
+            .save(&env);
         }
 
         pub fn set_balances(env: Env, new_balances: (u128, u128)) -> Result<(), Error> {
             Pool::update(&env, |pool| {
                 pool.token_balances = DoubleU128::from(new_balances);
                 pool.total_lp_amount = pool.get_current_d();
+                pool.d = pool.total_lp_amount;
                 Ok(())
             })
         }
@@ -260,4 +461,33 @@ mod tests {
         assert_eq!(input, calc_input);
         assert_eq!(fee, calc_fee);
     }
+
+    #[test]
+    fn test_send_whole_balance() {
+        let env = Env::default();
+
+        let test_pool_id = env.register_contract(None, TestPool);
+        let pool = TestPoolClient::new(&env, &test_pool_id);
+        pool.init();
+        pool.set_balances(&(200_000_000, 200_000_000));
+
+        // With the 1% fee the output drains the balance of token B
+        let result = pool.try_get_send_amount(&198_000_0000000, &Token::B);
+
+        assert_eq!(result, Err(Ok(Error::InsufficientReceivedAmount)));
+    }
+
+    #[test]
+    fn test_send_above_balance() {
+        let env = Env::default();
+
+        let test_pool_id = env.register_contract(None, TestPool);
+        let pool = TestPoolClient::new(&env, &test_pool_id);
+        pool.init();
+        pool.set_balances(&(200_000_000, 200_000_000));
+
+        let result = pool.try_get_send_amount(&300_000_0000000, &Token::B);
+
+        assert_eq!(result, Err(Ok(Error::InsufficientReceivedAmount)));
+    }
 }
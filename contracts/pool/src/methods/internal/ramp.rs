@@ -0,0 +1,80 @@
+use shared::{require, Error};
+use soroban_sdk::Env;
+
+use crate::storage::pool::Pool;
+
+impl Pool {
+    /// A ramp lasts at least a day
+    pub const MIN_RAMP_TIME: u64 = 24 * 60 * 60;
+
+    /// A ramp changes A by 10x at most
+    pub const MAX_A_CHANGE: u128 = 10;
+
+    /// A of the ramp at `now`
+    pub fn get_a(&self, now: u64) -> u128 {
+        if now >= self.ramp_end {
+            return self.future_a;
+        }
+
+        let elapsed = (now - self.ramp_start) as u128;
+        let duration = (self.ramp_end - self.ramp_start) as u128;
+
+        if self.future_a > self.initial_a {
+            self.initial_a + (self.future_a - self.initial_a) * elapsed / duration
+        } else {
+            self.initial_a - (self.initial_a - self.future_a) * elapsed / duration
+        }
+    }
+
+    /// Moves A along the ramp, the invariant of the balances is recomputed at the new A
+    /// while the LP amount stays, so the value of the LP follows D
+    pub fn sync_a(&mut self, env: &Env) {
+        let a = self.get_a(env.ledger().timestamp());
+
+        if a == self.a {
+            return;
+        }
+
+        self.a = a;
+
+        if self.total_lp_amount > 0 {
+            self.d = self.get_current_d();
+        }
+    }
+
+    /// Starts moving A from the current value to `future_a` linearly until `future_time`,
+    /// A must be synced
+    pub fn ramp_a(&mut self, env: &Env, future_a: u128, future_time: u64) -> Result<(), Error> {
+        let now = env.ledger().timestamp();
+
+        require!(
+            future_time >= now.saturating_add(Self::MIN_RAMP_TIME),
+            Error::InvalidArg
+        );
+        require!(
+            future_a > 0
+                && future_a <= self.a * Self::MAX_A_CHANGE
+                && future_a * Self::MAX_A_CHANGE >= self.a,
+            Error::InvalidArg
+        );
+        // A between the ends of the ramp is bounded by them
+        Self::check_overflow_bounds(future_a, self.max_token_balance).ok_or(Error::InvalidArg)?;
+
+        self.initial_a = self.a;
+        self.future_a = future_a;
+        self.ramp_start = now;
+        self.ramp_end = future_time;
+
+        Ok(())
+    }
+
+    /// Keeps A at its current value, A must be synced
+    pub fn stop_ramp_a(&mut self, env: &Env) {
+        let now = env.ledger().timestamp();
+
+        self.initial_a = self.a;
+        self.future_a = self.a;
+        self.ramp_start = now;
+        self.ramp_end = now;
+    }
+}
@@ -0,0 +1,99 @@
+use core::cmp::Ordering;
+
+use shared::{require, Error};
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage::{common::RateSource, pool::Pool};
+
+impl Pool {
+    /// Rates are fixed point numbers with 18 decimals
+    pub const RATE_ONE: u128 = 1_000_000_000_000_000_000;
+
+    pub fn set_rate_providers(
+        &mut self,
+        env: &Env,
+        rate_providers: (Option<Address>, Option<Address>),
+        rate_max_age: u64,
+    ) -> Result<(), Error> {
+        // Rates reprice the balances, so they are only set on an empty pool
+        require!(self.total_lp_amount == 0, Error::Forbidden);
+        // The base pool LP of a metapool is always priced by the base pool
+        require!(self.base_pool().is_none(), Error::Forbidden);
+
+        self.rate_sources = (rate_providers.0.into(), rate_providers.1.into());
+        self.rate_max_age = rate_max_age;
+
+        for index in 0..2 {
+            self.rates[index] = self.fetch_rate(env, index)?;
+        }
+
+        self.rates_updated_at = env.ledger().timestamp();
+
+        Ok(())
+    }
+
+    /// Fetches the rates again once the cached ones are older than `rate_max_age`.
+    /// Balances keep their rated value on a rate increase, so the tokens freed by it
+    /// are credited to the pool as rewards. On a rate decrease the balances are written down
+    /// to the rated value of the tokens backing them, the loss is taken by the LP
+    pub fn sync_rates(&mut self, env: &Env) -> Result<(), Error> {
+        let now = env.ledger().timestamp();
+
+        if self.rate_sources == (RateSource::Fixed, RateSource::Fixed)
+            || now < self.rates_updated_at.saturating_add(self.rate_max_age)
+        {
+            return Ok(());
+        }
+
+        let mut is_decreased = false;
+
+        for index in 0..2 {
+            if *self.rate_source(index) == RateSource::Fixed {
+                continue;
+            }
+
+            let rate = self.fetch_rate(env, index)?;
+            let backing = self.amount_from_system_precision(self.token_balances[index], index)?;
+            let old_rate = self.rates[index];
+            self.rates[index] = rate;
+
+            match rate.cmp(&old_rate) {
+                Ordering::Greater => {
+                    let new_backing =
+                        self.amount_from_system_precision(self.token_balances[index], index)?;
+                    self.add_rewards(backing - new_backing, index.into())?;
+                }
+                Ordering::Less => {
+                    self.token_balances[index] = self.amount_to_system_precision(backing, index)?;
+                    is_decreased = true;
+                }
+                Ordering::Equal => {}
+            }
+        }
+
+        if is_decreased && self.total_lp_amount > 0 {
+            self.d = self.get_current_d();
+        }
+
+        self.rates_updated_at = now;
+
+        Ok(())
+    }
+
+    fn fetch_rate(&self, env: &Env, index: usize) -> Result<u128, Error> {
+        let rate = match self.rate_source(index) {
+            RateSource::Fixed => return Ok(Self::RATE_ONE),
+            RateSource::Provider(provider) => {
+                env.invoke_contract(provider, &Symbol::new(env, "get_rate"), Vec::new(env))
+            }
+            RateSource::BasePool(base_pool) => env.invoke_contract(
+                base_pool,
+                &Symbol::new(env, "get_virtual_price"),
+                Vec::new(env),
+            ),
+        };
+        require!(rate > 0, Error::InvalidArg);
+
+        Ok(rate)
+    }
+}
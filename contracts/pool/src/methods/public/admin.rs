@@ -32,3 +32,75 @@ pub fn set_admin_fee_share(env: Env, admin_fee_share_bp: u128) -> Result<(), Err
         Ok(())
     })
 }
+
+/// `set_fee_share` authorized by the factory instead of the admin
+pub fn set_fee_share_by_factory(env: Env, fee_share_bp: u128) -> Result<(), Error> {
+    require!(fee_share_bp < Pool::BP, Error::InvalidArg);
+
+    Pool::update(&env, |pool| {
+        pool.factory.require_auth();
+        pool.fee_share_bp = fee_share_bp;
+        Ok(())
+    })
+}
+
+/// `set_admin_fee_share` authorized by the factory instead of the admin
+pub fn set_admin_fee_share_by_factory(env: Env, admin_fee_share_bp: u128) -> Result<(), Error> {
+    require!(admin_fee_share_bp < Pool::BP, Error::InvalidArg);
+
+    Pool::update(&env, |pool| {
+        pool.factory.require_auth();
+        pool.admin_fee_share_bp = admin_fee_share_bp;
+        Ok(())
+    })
+}
+
+pub fn ramp_a(env: Env, future_a: u128, future_time: u64) -> Result<(), Error> {
+    Pool::update(&env, |pool| {
+        pool.factory.require_auth();
+        pool.sync_a(&env);
+        pool.ramp_a(&env, future_a, future_time)
+    })
+}
+
+pub fn stop_ramp_a(env: Env) -> Result<(), Error> {
+    Pool::update(&env, |pool| {
+        pool.factory.require_auth();
+        pool.sync_a(&env);
+        pool.stop_ramp_a(&env);
+        Ok(())
+    })
+}
+
+pub fn set_paused(env: Env, paused: bool) -> Result<(), Error> {
+    Pool::update(&env, |pool| {
+        pool.factory.require_auth();
+        pool.paused = paused;
+        Ok(())
+    })
+}
+
+/// Protocol share of the pool fees, pushed by the factory
+pub fn set_protocol_fee_share(env: Env, share_bp: u128) -> Result<(), Error> {
+    require!(
+        share_bp <= Pool::MAX_PROTOCOL_FEE_SHARE_BP,
+        Error::InvalidArg
+    );
+
+    Pool::update(&env, |pool| {
+        pool.factory.require_auth();
+        pool.protocol_fee_share_bp = share_bp;
+        Ok(())
+    })
+}
+
+pub fn set_rate_providers(
+    env: Env,
+    rate_providers: (Option<Address>, Option<Address>),
+    rate_max_age: u64,
+) -> Result<(), Error> {
+    Pool::update(&env, |pool| {
+        pool.factory.require_auth();
+        pool.set_rate_providers(&env, rate_providers, rate_max_age)
+    })
+}
@@ -1,8 +1,8 @@
-use shared::{soroban_data::SimpleSorobanData, utils::safe_cast, Error};
-use soroban_sdk::Env;
+use shared::{require, soroban_data::SimpleSorobanData, Error};
+use soroban_sdk::{Address, Env};
 use storage::Admin;
 
-use crate::storage::pool::Pool;
+use crate::storage::{common::Token, double_values::DoubleU128, pool::Pool};
 
 pub fn claim_admin_fee(env: Env) -> Result<(), Error> {
     let admin = Admin::get(&env)?;
@@ -12,11 +12,13 @@ pub fn claim_admin_fee(env: Env) -> Result<(), Error> {
 
     for (index, _) in pool.tokens.to_array().into_iter().enumerate() {
         if pool.admin_fee_amount[index] > 0 {
-            pool.get_token_by_index(&env, index).transfer(
+            pool.transfer(
+                &env,
+                index,
                 &env.current_contract_address(),
                 admin.as_ref(),
-                &safe_cast(pool.admin_fee_amount[index])?,
-            );
+                pool.admin_fee_amount[index],
+            )?;
             pool.admin_fee_amount[index] = 0;
             pool.save(&env);
         }
@@ -24,3 +26,63 @@ pub fn claim_admin_fee(env: Env) -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Sends `amounts` out of the admin fee to `recipient`.
+/// With `convert_to` the claimed fee of the other token is swapped through the pool
+/// and everything is sent in `convert_to`, at least `receive_amount_min` of it
+pub fn claim_admin_fee_to(
+    env: Env,
+    recipient: Address,
+    amounts: (u128, u128),
+    convert_to: Option<Token>,
+    receive_amount_min: u128,
+) -> Result<(u128, u128), Error> {
+    let admin = Admin::get(&env)?;
+    admin.require_auth();
+
+    let mut pool = Pool::get(&env)?;
+    let mut amounts = DoubleU128::from(amounts);
+
+    for index in 0..2 {
+        require!(
+            amounts[index] <= pool.admin_fee_amount[index],
+            Error::NotEnoughAmount
+        );
+        pool.admin_fee_amount[index] -= amounts[index];
+    }
+
+    if let Some(token_to) = convert_to {
+        let token_from = token_to.opposite();
+
+        if amounts[token_from] > 0 {
+            require!(!pool.paused, Error::Paused);
+            pool.sync_a(&env);
+            pool.sync_rates(&env)?;
+
+            let receive_amount = pool.apply_swap(amounts[token_from], token_from)?;
+            amounts[token_from] = 0;
+            amounts[token_to] += receive_amount.output;
+        }
+
+        require!(
+            amounts[token_to] >= receive_amount_min,
+            Error::InsufficientReceivedAmount
+        );
+    }
+
+    for (index, amount) in amounts.to_array().into_iter().enumerate() {
+        if amount > 0 {
+            pool.transfer(
+                &env,
+                index,
+                &env.current_contract_address(),
+                &recipient,
+                amount,
+            )?;
+        }
+    }
+
+    pool.save(&env);
+
+    Ok(amounts.data)
+}
@@ -0,0 +1,68 @@
+use shared::{soroban_data::SimpleSorobanData, utils::safe_cast, Error};
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    token, vec, Env, IntoVal, Symbol, Vec,
+};
+use storage::Admin;
+
+use crate::storage::{common::Token, pool::Pool};
+
+/// Rewards of the base pool earned by the LP held by the metapool are deposited back
+/// into the base pool, the received base LP is credited to the metapool LPs as rewards
+pub fn claim_base_rewards(env: Env) -> Result<(u128, u128), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    let mut pool = Pool::get(&env)?;
+    let base_pool = pool.base_pool().cloned().ok_or(Error::InvalidArg)?;
+    let current_contract = env.current_contract_address();
+
+    env.invoke_contract::<()>(
+        &base_pool,
+        &Symbol::new(&env, "claim_rewards"),
+        vec![&env, current_contract.into_val(&env)],
+    );
+
+    let base: Pool = env.invoke_contract(&base_pool, &Symbol::new(&env, "get_pool"), vec![&env]);
+    let mut claimed = (0u128, 0u128);
+    // The base pool takes the tokens from the metapool on its behalf
+    let mut transfers = Vec::new(&env);
+
+    for (index, token) in base.tokens.to_array().into_iter().enumerate() {
+        let balance = token::Client::new(&env, &token).balance(&current_contract);
+
+        if balance > 0 {
+            transfers.push_back(InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token,
+                    fn_name: Symbol::new(&env, "transfer"),
+                    args: (current_contract.clone(), base_pool.clone(), balance).into_val(&env),
+                },
+                sub_invocations: Vec::new(&env),
+            }));
+        }
+
+        match index {
+            0 => claimed.0 = safe_cast(balance)?,
+            _ => claimed.1 = safe_cast(balance)?,
+        }
+    }
+
+    if claimed != (0, 0) {
+        env.authorize_as_current_contract(transfers);
+        let lp_amount: u128 = env.invoke_contract(
+            &base_pool,
+            &Symbol::new(&env, "deposit"),
+            vec![
+                &env,
+                current_contract.into_val(&env),
+                claimed.into_val(&env),
+                0u128.into_val(&env),
+            ],
+        );
+
+        pool.add_rewards(lp_amount, Token::B)?;
+        pool.save(&env);
+    }
+
+    Ok(claimed)
+}
@@ -0,0 +1,30 @@
+use shared::{soroban_data::SimpleSorobanData, Error};
+use soroban_sdk::{Address, Env};
+
+use crate::storage::pool::Pool;
+
+pub fn claim_protocol_fee(env: Env, recipient: Address) -> Result<(u128, u128), Error> {
+    let mut pool = Pool::get(&env)?;
+    pool.factory.require_auth();
+
+    let claimed = pool.protocol_fee_amount.clone();
+
+    for (index, amount) in claimed.to_array().into_iter().enumerate() {
+        if amount > 0 {
+            pool.transfer(
+                &env,
+                index,
+                &env.current_contract_address(),
+                &recipient,
+                amount,
+            )?;
+        }
+    }
+
+    if !claimed.is_zero() {
+        pool.protocol_fee_amount = Default::default();
+        pool.save(&env);
+    }
+
+    Ok(claimed.data)
+}
@@ -2,28 +2,73 @@ use shared::{soroban_data::SimpleSorobanData, Error, Event};
 use soroban_sdk::{Address, Env};
 
 use crate::{
-    events::RewardsClaimed,
-    storage::{pool::Pool, user_deposit::UserDeposit},
+    events::{OperatorRemoved, OperatorSet, RewardsClaimed},
+    storage::{operator::Operator, pool::Pool, user_deposit::UserDeposit},
 };
 
 pub fn claim_rewards(env: Env, sender: Address) -> Result<(), Error> {
     sender.require_auth();
-    let pool = Pool::get(&env)?;
 
-    let mut user_deposit = UserDeposit::get(&env, sender.clone());
-    let rewards = pool.claim_rewards(&env, sender.clone(), &mut user_deposit)?;
+    claim_rewards_to(&env, sender.clone(), sender)
+}
 
-    if rewards.to_array().into_iter().sum::<u128>() == 0 {
-        return Ok(());
+/// Claims the rewards of `owner` to `recipient`, callable by the operator of the owner only
+pub fn claim_rewards_for(env: Env, owner: Address, recipient: Address) -> Result<(), Error> {
+    Operator::require_auth(&env, &owner)?;
+
+    claim_rewards_to(&env, owner, recipient)
+}
+
+/// Sets the address allowed to claim the sender rewards to any recipient, `None` removes it
+pub fn set_operator(env: Env, sender: Address, operator: Option<Address>) -> Result<(), Error> {
+    sender.require_auth();
+
+    Operator::set(&env, &sender, operator.clone());
+
+    match operator {
+        Some(operator) => OperatorSet {
+            user: sender,
+            operator,
+        }
+        .publish(&env),
+        None => OperatorRemoved { user: sender }.publish(&env),
     }
 
-    user_deposit.save(&env, sender.clone());
+    Ok(())
+}
+
+fn claim_rewards_to(env: &Env, owner: Address, recipient: Address) -> Result<(), Error> {
+    let pool = Pool::get(env)?;
+    let mut user_deposit = UserDeposit::get(env, owner.clone());
+
+    if do_claim_rewards(env, &pool, owner.clone(), recipient, &mut user_deposit)? {
+        user_deposit.save(env, owner);
+    }
+
+    Ok(())
+}
+
+/// Claims the rewards of `owner` from the loaded pool to `recipient`,
+/// returns whether anything was claimed and `user_deposit` has to be saved
+pub(crate) fn do_claim_rewards(
+    env: &Env,
+    pool: &Pool,
+    owner: Address,
+    recipient: Address,
+    user_deposit: &mut UserDeposit,
+) -> Result<bool, Error> {
+    let rewards = pool.claim_rewards(env, recipient.clone(), user_deposit)?;
+
+    if rewards.to_array().into_iter().sum::<u128>() == 0 {
+        return Ok(false);
+    }
 
     RewardsClaimed {
-        user: sender,
+        user: owner,
+        recipient,
         rewards: rewards.data,
     }
-    .publish(&env);
+    .publish(env);
 
-    Ok(())
+    Ok(true)
 }
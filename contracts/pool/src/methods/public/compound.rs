@@ -0,0 +1,75 @@
+use shared::{require, soroban_data::SimpleSorobanData, Error, Event};
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events::{RewardsClaimed, RewardsCompounded},
+    storage::{double_values::DoubleU128, pool::Pool, user_deposit::UserDeposit},
+};
+
+/// Deposits the pending rewards of the sender back as liquidity, returns the minted LP
+pub fn compound_rewards(env: Env, sender: Address, min_lp_amount: u128) -> Result<u128, Error> {
+    sender.require_auth();
+    let mut pool = Pool::get(&env)?;
+    require!(!pool.paused, Error::Paused);
+    pool.sync_a(&env);
+    pool.sync_rates(&env)?;
+    let mut user_deposit = UserDeposit::get(&env, sender.clone());
+
+    let rewards = pool.get_pending(&user_deposit)?;
+    let deposit_amount = pool.get_deposit_amount(rewards.clone())?;
+    require!(deposit_amount.lp_amount >= min_lp_amount, Error::Slippage);
+
+    let lp_amount = pool.compound(&env, &sender, &mut user_deposit, deposit_amount)?;
+
+    pool.save(&env);
+    user_deposit.save(&env, sender.clone());
+
+    RewardsCompounded {
+        user: sender,
+        rewards: rewards.data,
+        lp_amount,
+    }
+    .publish(&env);
+
+    Ok(lp_amount)
+}
+
+/// Opts the sender in or out of compounding the rewards settled on deposit and withdraw
+pub fn set_auto_compound(env: Env, sender: Address, enabled: bool) -> Result<(), Error> {
+    sender.require_auth();
+
+    let mut user_deposit = UserDeposit::get(&env, sender.clone());
+    user_deposit.auto_compound = enabled;
+    user_deposit.save(&env, sender);
+
+    Ok(())
+}
+
+/// Publishes the rewards settled on a deposit or withdraw as claimed to `recipient` or compounded
+pub(crate) fn publish_settled_rewards(
+    env: &Env,
+    user: Address,
+    recipient: Address,
+    rewards: DoubleU128,
+    compounded_lp: u128,
+) {
+    if rewards.is_zero() {
+        return;
+    }
+
+    if compounded_lp > 0 {
+        RewardsCompounded {
+            user,
+            rewards: rewards.data,
+            lp_amount: compounded_lp,
+        }
+        .publish(env);
+    } else {
+        RewardsClaimed {
+            user,
+            recipient,
+            rewards: rewards.data,
+        }
+        .publish(env);
+    }
+}
@@ -1,8 +1,9 @@
 use shared::{soroban_data::SimpleSorobanData, Error, Event};
 use soroban_sdk::{Address, Env};
 
+use super::publish_settled_rewards;
 use crate::{
-    events::{Deposit, RewardsClaimed},
+    events::Deposit,
     storage::{double_values::DoubleU128, pool::Pool, user_deposit::UserDeposit},
 };
 
@@ -11,37 +12,79 @@ pub fn deposit(
     sender: Address,
     amounts: (u128, u128),
     min_lp_amount: u128,
-) -> Result<(), Error> {
+) -> Result<u128, Error> {
+    deposit_for(env, sender.clone(), sender, amounts, min_lp_amount)
+}
+
+/// Deposits the tokens of `sender` and credits the LP to `beneficiary`,
+/// the pending rewards of the beneficiary are paid to the beneficiary
+pub fn deposit_for(
+    env: Env,
+    sender: Address,
+    beneficiary: Address,
+    amounts: (u128, u128),
+    min_lp_amount: u128,
+) -> Result<u128, Error> {
     sender.require_auth();
     let mut pool = Pool::get(&env)?;
-    let mut user_deposit = UserDeposit::get(&env, sender.clone());
-    let amounts = DoubleU128::from(amounts);
+    pool.sync_a(&env);
+    pool.sync_rates(&env)?;
+    let mut user_deposit = UserDeposit::get(&env, beneficiary.clone());
 
-    let (rewards, lp_amount) = pool.deposit(
+    let lp_amount = do_deposit(
         &env,
-        amounts.clone(),
-        sender.clone(),
+        &mut pool,
+        sender,
+        beneficiary.clone(),
         &mut user_deposit,
+        amounts,
         min_lp_amount,
     )?;
 
     pool.save(&env);
-    user_deposit.save(&env, sender.clone());
+    user_deposit.save(&env, beneficiary);
+
+    Ok(lp_amount)
+}
+
+/// Deposits into the loaded pool with synced rates, the caller saves the pool
+/// and the beneficiary `user_deposit`. Returns the LP minted for the amounts,
+/// the auto-compounded rewards are not included
+pub(crate) fn do_deposit(
+    env: &Env,
+    pool: &mut Pool,
+    sender: Address,
+    beneficiary: Address,
+    user_deposit: &mut UserDeposit,
+    amounts: (u128, u128),
+    min_lp_amount: u128,
+) -> Result<u128, Error> {
+    let amounts = DoubleU128::from(amounts);
+
+    let (rewards, lp_amount, compounded_lp) = pool.deposit(
+        env,
+        amounts.clone(),
+        sender.clone(),
+        beneficiary.clone(),
+        user_deposit,
+        min_lp_amount,
+    )?;
 
     Deposit {
-        user: sender.clone(),
+        user: sender,
+        beneficiary: beneficiary.clone(),
         lp_amount,
         amounts: amounts.data,
     }
-    .publish(&env);
-
-    if !rewards.is_zero() {
-        RewardsClaimed {
-            user: sender,
-            rewards: rewards.data,
-        }
-        .publish(&env);
-    }
+    .publish(env);
+
+    publish_settled_rewards(
+        env,
+        beneficiary.clone(),
+        beneficiary,
+        rewards,
+        compounded_lp,
+    );
 
-    Ok(())
+    Ok(lp_amount)
 }
@@ -0,0 +1,123 @@
+use shared::{require, soroban_data::SimpleSorobanData, utils::safe_cast, Error, Event};
+use soroban_sdk::{token, Address, Env, Vec};
+use storage::Admin;
+
+use crate::{
+    events::{RewardNotified, RewardTokenClaimed, RewardsClaimed},
+    storage::{
+        pool::Pool,
+        reward::{RewardSchedule, RewardTokens},
+        user_deposit::UserDeposit,
+    },
+};
+
+pub fn add_reward_token(env: Env, token: Address) -> Result<(), Error> {
+    Admin::require_exist_auth(&env)?;
+
+    let pool = Pool::get(&env)?;
+    // Pool tokens are paid out by the swap fee rewards
+    require!(!pool.tokens.to_array().contains(&token), Error::InvalidArg);
+
+    let mut tokens = RewardTokens::get_or_default(&env);
+    require!(!tokens.contains(&token), Error::InvalidArg);
+    require!(
+        tokens.len() < RewardTokens::MAX_REWARD_TOKENS,
+        Error::Forbidden
+    );
+
+    RewardSchedule {
+        last_update: env.ledger().timestamp(),
+        ..Default::default()
+    }
+    .save(&env, &token);
+    tokens.push_back(token);
+    RewardTokens(tokens).save(&env);
+
+    Ok(())
+}
+
+/// Streams `amount` sent by the admin plus the rest of the current period over `duration` seconds
+pub fn notify_reward_amount(
+    env: Env,
+    token: Address,
+    amount: u128,
+    duration: u64,
+) -> Result<(), Error> {
+    let admin = Admin::get(&env)?;
+    admin.require_auth();
+    require!(duration > 0, Error::InvalidArg);
+
+    let pool = Pool::get(&env)?;
+    let now = env.ledger().timestamp();
+    let mut schedule = RewardSchedule::get(&env, &token)?;
+    schedule.update(now, pool.total_reward_shares)?;
+
+    let leftover = if now < schedule.period_finish {
+        (schedule.period_finish - now) as u128 * schedule.rate
+    } else {
+        0
+    };
+
+    schedule.rate = (amount + leftover) / duration as u128;
+    require!(schedule.rate > 0, Error::ZeroAmount);
+    schedule.period_finish = now + duration;
+    schedule.save(&env, &token);
+
+    token::Client::new(&env, &token).transfer(
+        admin.as_ref(),
+        &env.current_contract_address(),
+        &safe_cast(amount)?,
+    );
+
+    RewardNotified {
+        token,
+        amount,
+        rate: schedule.rate,
+        period_finish: schedule.period_finish,
+    }
+    .publish(&env);
+
+    Ok(())
+}
+
+/// Claims the pool token rewards and all reward tokens, returns the claimed amounts
+/// with the pool tokens first
+pub fn claim_all(env: Env, sender: Address) -> Result<Vec<(Address, u128)>, Error> {
+    sender.require_auth();
+    let pool = Pool::get(&env)?;
+
+    let mut user_deposit = UserDeposit::get(&env, sender.clone());
+    let rewards = pool.claim_rewards(&env, sender.clone(), &mut user_deposit)?;
+    let reward_tokens = pool.claim_reward_tokens(&env, &sender, user_deposit.reward_shares())?;
+
+    if !rewards.is_zero() {
+        user_deposit.save(&env, sender.clone());
+
+        RewardsClaimed {
+            user: sender.clone(),
+            recipient: sender.clone(),
+            rewards: rewards.data,
+        }
+        .publish(&env);
+    }
+
+    let mut claimed = Vec::new(&env);
+    for (index, token) in pool.tokens.to_array().into_iter().enumerate() {
+        claimed.push_back((token, rewards[index]));
+    }
+
+    for (token, amount) in reward_tokens.iter() {
+        if amount > 0 {
+            RewardTokenClaimed {
+                user: sender.clone(),
+                token: token.clone(),
+                amount,
+            }
+            .publish(&env);
+        }
+
+        claimed.push_back((token, amount));
+    }
+
+    Ok(claimed)
+}
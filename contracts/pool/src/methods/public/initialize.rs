@@ -1,8 +1,8 @@
 use shared::{require, soroban_data::SimpleSorobanData, Error};
-use soroban_sdk::{token, Address, Env};
+use soroban_sdk::{token, Address, Env, Symbol, Vec};
 use storage::Admin;
 
-use crate::storage::pool::Pool;
+use crate::storage::{common::RateSource, pool::Pool};
 
 #[allow(clippy::too_many_arguments)]
 pub fn initialize(
@@ -13,6 +13,9 @@ pub fn initialize(
     token_b: Address,
     fee_share_bp: u128,
     admin_fee_share_bp: u128,
+    factory: Address,
+    system_precision: u32,
+    max_token_balance: u128,
 ) -> Result<(), Error> {
     require!(!Pool::has(&env), Error::Initialized);
 
@@ -22,15 +25,67 @@ pub fn initialize(
     let decimals_a = token::Client::new(&env, &token_a).decimals();
     let decimals_b = token::Client::new(&env, &token_b).decimals();
 
-    Pool::from_init_params(
+    let pool = Pool::from_init_params(
         a,
         token_a,
         token_b,
         (decimals_a, decimals_b),
+        system_precision,
+        max_token_balance,
         fee_share_bp,
         admin_fee_share_bp,
-    )
-    .save(&env);
+        factory,
+    );
+    pool.validate_precision()?;
+    pool.save(&env);
+    Admin(admin).save(&env);
+
+    Ok(())
+}
+
+/// Token B of a metapool is the LP of `base_pool`, kept in the base pool system precision
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_metapool(
+    env: Env,
+    admin: Address,
+    a: u128,
+    token: Address,
+    base_pool: Address,
+    fee_share_bp: u128,
+    admin_fee_share_bp: u128,
+    factory: Address,
+    system_precision: u32,
+    max_token_balance: u128,
+) -> Result<(), Error> {
+    require!(!Pool::has(&env), Error::Initialized);
+
+    require!(fee_share_bp < Pool::BP, Error::InvalidArg);
+    require!(admin_fee_share_bp < Pool::BP, Error::InvalidArg);
+
+    let base: Pool =
+        env.invoke_contract(&base_pool, &Symbol::new(&env, "get_pool"), Vec::new(&env));
+    require!(base.base_pool().is_none(), Error::InvalidArg);
+    require!(!base.tokens.to_array().contains(&token), Error::InvalidArg);
+
+    let decimals = token::Client::new(&env, &token).decimals();
+
+    let mut pool = Pool::from_init_params(
+        a,
+        token,
+        base_pool.clone(),
+        (decimals, base.system_precision),
+        system_precision,
+        max_token_balance,
+        fee_share_bp,
+        admin_fee_share_bp,
+        factory,
+    );
+    pool.rate_sources.1 = RateSource::BasePool(base_pool);
+    pool.rates[1] = base.get_virtual_price();
+    pool.rates_updated_at = env.ledger().timestamp();
+
+    pool.validate_precision()?;
+    pool.save(&env);
     Admin(admin).save(&env);
 
     Ok(())
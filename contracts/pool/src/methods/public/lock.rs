@@ -0,0 +1,102 @@
+use shared::{require, soroban_data::SimpleSorobanData, Error, Event};
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events::{Locked, RewardsClaimed, Unlocked},
+    storage::{double_values::DoubleU128, pool::Pool, user_deposit::UserDeposit},
+};
+
+/// Locks `lp_amount` more of the sender LP for `months`, the whole locked amount
+/// gets the new weight and unlocks at the new time which can't be earlier than the current one
+pub fn lock(env: Env, sender: Address, lp_amount: u128, months: u32) -> Result<(), Error> {
+    sender.require_auth();
+    require!(
+        months > 0 && months <= Pool::MAX_LOCK_MONTHS,
+        Error::InvalidArg
+    );
+    require!(lp_amount > 0, Error::ZeroAmount);
+
+    let mut pool = Pool::get(&env)?;
+    let mut user_deposit = UserDeposit::get(&env, sender.clone());
+    let now = env.ledger().timestamp();
+
+    require!(
+        user_deposit.unlocked_amount(now) >= lp_amount,
+        Error::NotEnoughAmount
+    );
+
+    let unlock_time = now + Pool::LOCK_MONTH * months as u64;
+    require!(unlock_time >= user_deposit.unlock_time, Error::InvalidArg);
+
+    let weight_bp = Pool::lock_weight_bp(months);
+    let rewards = pool.update_user_deposit(&env, &sender, &mut user_deposit, |user_deposit| {
+        user_deposit.release_expired_lock(now);
+        user_deposit.locked_amount += lp_amount;
+        user_deposit.unlock_time = unlock_time;
+        user_deposit.lock_weight_bp = weight_bp;
+    })?;
+
+    pay_rewards(&env, &pool, &sender, &rewards)?;
+
+    pool.save(&env);
+    user_deposit.save(&env, sender.clone());
+
+    Locked {
+        user: sender,
+        lp_amount,
+        unlock_time,
+        weight_bp,
+    }
+    .publish(&env);
+
+    Ok(())
+}
+
+/// Drops the expired lock of the user and its boost, callable by anyone
+pub fn unlock(env: Env, user: Address) -> Result<(), Error> {
+    let mut pool = Pool::get(&env)?;
+    let mut user_deposit = UserDeposit::get(&env, user.clone());
+    let now = env.ledger().timestamp();
+    let lp_amount = user_deposit.locked_amount;
+
+    require!(
+        lp_amount > 0 && now >= user_deposit.unlock_time,
+        Error::LpLocked
+    );
+
+    let rewards = pool.update_user_deposit(&env, &user, &mut user_deposit, |user_deposit| {
+        user_deposit.release_expired_lock(now);
+    })?;
+
+    pay_rewards(&env, &pool, &user, &rewards)?;
+
+    pool.save(&env);
+    user_deposit.save(&env, user.clone());
+
+    Unlocked { user, lp_amount }.publish(&env);
+
+    Ok(())
+}
+
+fn pay_rewards(env: &Env, pool: &Pool, user: &Address, rewards: &DoubleU128) -> Result<(), Error> {
+    if rewards.is_zero() {
+        return Ok(());
+    }
+
+    let current_contract = env.current_contract_address();
+
+    for (index, reward) in rewards.to_array().into_iter().enumerate() {
+        if reward > 0 {
+            pool.transfer(env, index, &current_contract, user, reward)?;
+        }
+    }
+
+    RewardsClaimed {
+        user: user.clone(),
+        recipient: user.clone(),
+        rewards: rewards.data,
+    }
+    .publish(env);
+
+    Ok(())
+}
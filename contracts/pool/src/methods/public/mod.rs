@@ -1,15 +1,33 @@
+mod admin;
 mod claim_admin_fee;
+mod claim_base_rewards;
+mod claim_protocol_fee;
 mod claim_rewards;
+mod compound;
 mod deposit;
+mod gauge;
 mod initialize;
-mod admin;
+mod lock;
+mod multicall;
+mod signed_swap;
 mod swap;
+mod swap_underlying;
+mod transfer_lp;
 mod withdraw;
 
+pub use admin::*;
 pub use claim_admin_fee::*;
+pub use claim_base_rewards::*;
+pub use claim_protocol_fee::*;
 pub use claim_rewards::*;
+pub use compound::*;
 pub use deposit::*;
+pub use gauge::*;
 pub use initialize::*;
-pub use admin::*;
+pub use lock::*;
+pub use multicall::*;
+pub use signed_swap::*;
 pub use swap::*;
+pub use swap_underlying::*;
+pub use transfer_lp::*;
 pub use withdraw::*;
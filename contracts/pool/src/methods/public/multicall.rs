@@ -0,0 +1,74 @@
+use shared::{require, soroban_data::SimpleSorobanData, Error};
+use soroban_sdk::{Address, Env, Vec};
+
+use super::{do_claim_rewards, do_deposit, do_swap, do_withdraw};
+use crate::storage::{common::PoolOp, pool::Pool, user_deposit::UserDeposit};
+
+/// Executes `ops` in order against the pool loaded once, the pool and the sender deposit
+/// are saved at the end and any failed operation reverts all of them.
+/// Returns the received amount of every swap, 0 for the other operations
+pub fn multicall(env: Env, sender: Address, ops: Vec<PoolOp>) -> Result<Vec<u128>, Error> {
+    sender.require_auth();
+    require!(!ops.is_empty(), Error::InvalidArg);
+
+    let mut pool = Pool::get(&env)?;
+    pool.sync_a(&env);
+    pool.sync_rates(&env)?;
+    let mut user_deposit = UserDeposit::get(&env, sender.clone());
+    let mut results = Vec::new(&env);
+
+    for op in ops {
+        let result = match op {
+            PoolOp::Deposit(amounts, min_lp_amount) => {
+                do_deposit(
+                    &env,
+                    &mut pool,
+                    sender.clone(),
+                    sender.clone(),
+                    &mut user_deposit,
+                    amounts,
+                    min_lp_amount,
+                )?;
+                0
+            }
+            PoolOp::Withdraw(lp_amount, min_amounts) => {
+                do_withdraw(
+                    &env,
+                    &mut pool,
+                    sender.clone(),
+                    sender.clone(),
+                    &mut user_deposit,
+                    lp_amount,
+                    min_amounts,
+                )?;
+                0
+            }
+            PoolOp::Swap(amount_in, receive_amount_min, direction) => do_swap(
+                &env,
+                &mut pool,
+                sender.clone(),
+                sender.clone(),
+                amount_in,
+                receive_amount_min,
+                direction,
+            )?,
+            PoolOp::ClaimRewards => {
+                do_claim_rewards(
+                    &env,
+                    &pool,
+                    sender.clone(),
+                    sender.clone(),
+                    &mut user_deposit,
+                )?;
+                0
+            }
+        };
+
+        results.push_back(result);
+    }
+
+    pool.save(&env);
+    user_deposit.save(&env, sender);
+
+    Ok(results)
+}
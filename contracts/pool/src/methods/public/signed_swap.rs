@@ -0,0 +1,126 @@
+use shared::{require, soroban_data::SimpleSorobanData, utils::safe_cast, Error, Event};
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env};
+
+use crate::{
+    events::{IntentCancelled, IntentFilled, Swapped},
+    storage::{
+        common::RateSource,
+        intent::{IntentFill, IntentSigner, SwapIntent},
+        pool::Pool,
+    },
+};
+
+/// Sets the ed25519 key signing the swap intents of the owner
+pub fn set_intent_signer(env: Env, owner: Address, public_key: BytesN<32>) -> Result<(), Error> {
+    owner.require_auth();
+
+    IntentSigner(public_key).save(&env, &owner);
+
+    Ok(())
+}
+
+/// Fills `amount_in` of a signed intent, the tokens are taken through the owner allowance
+/// to the pool and the output is sent to the owner. Returns the output amount
+pub fn execute_signed_swap(
+    env: Env,
+    intent: SwapIntent,
+    signature: BytesN<64>,
+    amount_in: u128,
+) -> Result<u128, Error> {
+    let current_contract = env.current_contract_address();
+    require!(intent.pool == current_contract, Error::InvalidArg);
+    require!(
+        env.ledger().timestamp() <= intent.expiry,
+        Error::IntentExpired
+    );
+    require!(amount_in > 0, Error::ZeroAmount);
+
+    let public_key = IntentSigner::get(&env, &intent.owner)?;
+    env.crypto()
+        .ed25519_verify(&public_key, &intent.clone().to_xdr(&env), &signature);
+
+    let filled = IntentFill::get(&env, &intent.owner, intent.nonce)
+        .checked_add(amount_in)
+        .ok_or(Error::NotEnoughAmount)?;
+    require!(filled <= intent.amount_in, Error::NotEnoughAmount);
+
+    let mut pool = Pool::get(&env)?;
+    require!(!pool.paused, Error::Paused);
+    pool.sync_a(&env);
+    pool.sync_rates(&env)?;
+
+    let (token_from, token_to) = intent.direction.get_tokens();
+    // The base pool LP has no allowances
+    require!(
+        !matches!(
+            pool.rate_source(token_from as usize),
+            RateSource::BasePool(_)
+        ),
+        Error::Forbidden
+    );
+
+    pool.get_token_by_index(&env, token_from as usize)
+        .transfer_from(
+            &current_contract,
+            &intent.owner,
+            &current_contract,
+            &safe_cast(amount_in)?,
+        );
+
+    let receive_amount = pool.apply_swap(amount_in, token_from)?;
+
+    // Same price limit for every part, rounded up
+    let min_amount_out = intent
+        .min_amount_out
+        .checked_mul(amount_in)
+        .ok_or(Error::InvalidArg)?
+        .div_ceil(intent.amount_in);
+    require!(
+        receive_amount.output >= min_amount_out,
+        Error::InsufficientReceivedAmount
+    );
+
+    pool.transfer(
+        &env,
+        token_to as usize,
+        &current_contract,
+        &intent.owner,
+        receive_amount.output,
+    )?;
+
+    pool.save(&env);
+    IntentFill(filled).save(&env, &intent.owner, intent.nonce);
+
+    Swapped {
+        sender: intent.owner.clone(),
+        recipient: intent.owner.clone(),
+        from_token: pool.tokens[token_from].clone(),
+        to_token: pool.tokens[token_to].clone(),
+        from_amount: amount_in,
+        to_amount: receive_amount.output,
+        fee: receive_amount.fee,
+    }
+    .publish(&env);
+
+    IntentFilled {
+        owner: intent.owner,
+        nonce: intent.nonce,
+        amount_in,
+        amount_out: receive_amount.output,
+        filled,
+    }
+    .publish(&env);
+
+    Ok(receive_amount.output)
+}
+
+/// Stops any further fill of the owner intent with `nonce`
+pub fn cancel_intent(env: Env, owner: Address, nonce: u64) -> Result<(), Error> {
+    owner.require_auth();
+
+    IntentFill(u128::MAX).save(&env, &owner, nonce);
+
+    IntentCancelled { owner, nonce }.publish(&env);
+
+    Ok(())
+}
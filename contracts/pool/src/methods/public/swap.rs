@@ -16,11 +16,14 @@ pub fn swap(
 ) -> Result<u128, Error> {
     sender.require_auth();
     let mut pool = Pool::get(&env)?;
+    pool.sync_a(&env);
+    pool.sync_rates(&env)?;
 
-    let (to_amount, fee) = pool.swap(
+    let to_amount = do_swap(
         &env,
-        sender.clone(),
-        recipient.clone(),
+        &mut pool,
+        sender,
+        recipient,
         from_amount,
         receive_amount_min,
         direction,
@@ -28,6 +31,28 @@ pub fn swap(
 
     pool.save(&env);
 
+    Ok(to_amount)
+}
+
+/// Swaps on the loaded pool with synced rates, the caller saves the pool
+pub(crate) fn do_swap(
+    env: &Env,
+    pool: &mut Pool,
+    sender: Address,
+    recipient: Address,
+    from_amount: u128,
+    receive_amount_min: u128,
+    direction: Direction,
+) -> Result<u128, Error> {
+    let (to_amount, fee) = pool.swap(
+        env,
+        sender.clone(),
+        recipient.clone(),
+        from_amount,
+        receive_amount_min,
+        direction,
+    )?;
+
     let (token_from, token_to) = direction.get_tokens();
 
     Swapped {
@@ -39,7 +64,7 @@ pub fn swap(
         recipient,
         fee,
     }
-    .publish(&env);
+    .publish(env);
 
     Ok(to_amount)
 }
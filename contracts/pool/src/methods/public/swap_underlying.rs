@@ -0,0 +1,139 @@
+use shared::{require, soroban_data::SimpleSorobanData, utils::safe_cast, Error, Event};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Symbol, Vec};
+
+use crate::{
+    events::Swapped,
+    storage::{common::Direction, pool::Pool},
+};
+
+/// Swaps between the metapool token and a token of the base pool.
+/// The base pool is called on behalf of `sender`: a base token is deposited for the base LP
+/// which is swapped here, or the swapped base LP is withdrawn and the withdrawn other base
+/// token is swapped into the requested one
+pub fn swap_underlying(
+    env: Env,
+    sender: Address,
+    recipient: Address,
+    amount_in: u128,
+    receive_amount_min: u128,
+    token_from: Address,
+    token_to: Address,
+) -> Result<u128, Error> {
+    sender.require_auth();
+    let mut pool = Pool::get(&env)?;
+    let base_pool = pool.base_pool().cloned().ok_or(Error::InvalidArg)?;
+    pool.sync_a(&env);
+    pool.sync_rates(&env)?;
+
+    let base: Pool =
+        env.invoke_contract(&base_pool, &Symbol::new(&env, "get_pool"), Vec::new(&env));
+    let base_tokens = base.tokens.to_array();
+    let base_index = |token: &Address| base_tokens.iter().position(|t| t == token);
+
+    let (to_amount, fee) = match (base_index(&token_from), base_index(&token_to)) {
+        (Some(index), None) if token_to == pool.tokens[0] => {
+            let mut amounts = (0u128, 0u128);
+            match index {
+                0 => amounts.0 = amount_in,
+                _ => amounts.1 = amount_in,
+            }
+            // Only the minted LP is swapped, the rewards auto-compounded by the deposit
+            // stay with the sender
+            let lp_amount: u128 = env.invoke_contract(
+                &base_pool,
+                &Symbol::new(&env, "deposit"),
+                vec![
+                    &env,
+                    sender.into_val(&env),
+                    amounts.into_val(&env),
+                    0u128.into_val(&env),
+                ],
+            );
+
+            pool.swap(
+                &env,
+                sender.clone(),
+                recipient.clone(),
+                lp_amount,
+                receive_amount_min,
+                Direction::B2A,
+            )?
+        }
+        (None, Some(index)) if token_from == pool.tokens[0] => {
+            // Pending base rewards of the sender are paid out by the LP transfer,
+            // so the withdrawal below only sends the withdrawn tokens
+            let (lp_amount, fee) = pool.swap(
+                &env,
+                sender.clone(),
+                sender.clone(),
+                amount_in,
+                0,
+                Direction::A2B,
+            )?;
+
+            let token = token::Client::new(&env, &base_tokens[index]);
+            let other_index = 1 - index;
+            let other_token = token::Client::new(&env, &base_tokens[other_index]);
+            let balances_before = (token.balance(&sender), other_token.balance(&sender));
+
+            env.invoke_contract::<()>(
+                &base_pool,
+                &Symbol::new(&env, "withdraw"),
+                vec![&env, sender.into_val(&env), lp_amount.into_val(&env)],
+            );
+
+            let withdrawn: u128 = safe_cast(token.balance(&sender) - balances_before.0)?;
+            let other_withdrawn: u128 =
+                safe_cast(other_token.balance(&sender) - balances_before.1)?;
+
+            let swapped: u128 = if other_withdrawn > 0 {
+                let direction = match other_index {
+                    0 => Direction::A2B,
+                    _ => Direction::B2A,
+                };
+
+                env.invoke_contract(
+                    &base_pool,
+                    &Symbol::new(&env, "swap"),
+                    vec![
+                        &env,
+                        sender.into_val(&env),
+                        recipient.into_val(&env),
+                        other_withdrawn.into_val(&env),
+                        0u128.into_val(&env),
+                        direction.into_val(&env),
+                    ],
+                )
+            } else {
+                0
+            };
+
+            if withdrawn > 0 && sender != recipient {
+                token.transfer(&sender, &recipient, &safe_cast(withdrawn)?);
+            }
+
+            (withdrawn + swapped, fee)
+        }
+        _ => return Err(Error::InvalidArg),
+    };
+
+    require!(
+        to_amount >= receive_amount_min,
+        Error::InsufficientReceivedAmount
+    );
+
+    pool.save(&env);
+
+    Swapped {
+        from_token: token_from,
+        to_token: token_to,
+        from_amount: amount_in,
+        to_amount,
+        sender,
+        recipient,
+        fee,
+    }
+    .publish(&env);
+
+    Ok(to_amount)
+}
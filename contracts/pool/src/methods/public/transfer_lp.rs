@@ -0,0 +1,54 @@
+use shared::{require, soroban_data::SimpleSorobanData, Error, Event};
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events::{LpTransferred, RewardsClaimed},
+    storage::{pool::Pool, user_deposit::UserDeposit},
+};
+
+/// Moves LP between users, pending rewards of both are paid out at the current share.
+/// Not blocked by the pause, same as withdrawals
+pub fn transfer_lp(env: Env, from: Address, to: Address, lp_amount: u128) -> Result<(), Error> {
+    from.require_auth();
+    require!(from != to, Error::InvalidArg);
+
+    let mut pool = Pool::get(&env)?;
+    let current_contract = env.current_contract_address();
+    let mut from_deposit = UserDeposit::get(&env, from.clone());
+    let mut to_deposit = UserDeposit::get(&env, to.clone());
+
+    let from_rewards = pool.withdraw_lp(&env, &from, &mut from_deposit, lp_amount)?;
+    let to_rewards = pool.deposit_lp(&env, &to, &mut to_deposit, lp_amount)?;
+
+    for (user, rewards) in [(&from, &from_rewards), (&to, &to_rewards)] {
+        for (index, reward) in rewards.to_array().into_iter().enumerate() {
+            if reward > 0 {
+                pool.transfer(&env, index, &current_contract, user, reward)?;
+            }
+        }
+    }
+
+    pool.save(&env);
+    from_deposit.save(&env, from.clone());
+    to_deposit.save(&env, to.clone());
+
+    LpTransferred {
+        from: from.clone(),
+        to: to.clone(),
+        lp_amount,
+    }
+    .publish(&env);
+
+    for (user, rewards) in [(from, from_rewards), (to, to_rewards)] {
+        if !rewards.is_zero() {
+            RewardsClaimed {
+                user: user.clone(),
+                recipient: user,
+                rewards: rewards.data,
+            }
+            .publish(&env);
+        }
+    }
+
+    Ok(())
+}
@@ -1,37 +1,85 @@
-use shared::{soroban_data::SimpleSorobanData, Error, Event};
+use shared::{require, soroban_data::SimpleSorobanData, Error, Event};
 use soroban_sdk::{Address, Env};
 
+use super::publish_settled_rewards;
 use crate::{
-    events::{RewardsClaimed, Withdraw},
+    events::Withdraw,
     storage::{pool::Pool, user_deposit::UserDeposit},
 };
 
 pub fn withdraw(env: Env, sender: Address, lp_amount: u128) -> Result<(), Error> {
+    withdraw_to(env, sender.clone(), sender, lp_amount, (0, 0))
+}
+
+/// Burns the LP of `sender` and sends the tokens with the pending rewards to `recipient`,
+/// `min_amounts` are the least token amounts received without the rewards
+pub fn withdraw_to(
+    env: Env,
+    sender: Address,
+    recipient: Address,
+    lp_amount: u128,
+    min_amounts: (u128, u128),
+) -> Result<(), Error> {
     sender.require_auth();
     let mut pool = Pool::get(&env)?;
+    pool.sync_a(&env);
+    pool.sync_rates(&env)?;
     let mut user_deposit = UserDeposit::get(&env, sender.clone());
 
-    let (withdraw_amount, rewards) =
-        pool.withdraw(&env, sender.clone(), &mut user_deposit, lp_amount)?;
+    do_withdraw(
+        &env,
+        &mut pool,
+        sender.clone(),
+        recipient,
+        &mut user_deposit,
+        lp_amount,
+        min_amounts,
+    )?;
 
     pool.save(&env);
-    user_deposit.save(&env, sender.clone());
+    user_deposit.save(&env, sender);
+
+    Ok(())
+}
+
+/// Withdraws from the loaded pool, the caller saves the pool and the sender `user_deposit`
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn do_withdraw(
+    env: &Env,
+    pool: &mut Pool,
+    sender: Address,
+    recipient: Address,
+    user_deposit: &mut UserDeposit,
+    lp_amount: u128,
+    min_amounts: (u128, u128),
+) -> Result<(), Error> {
+    let can_compound = !pool.paused;
+
+    let (withdraw_amount, rewards, compounded_lp) = pool.withdraw(
+        env,
+        sender.clone(),
+        recipient.clone(),
+        user_deposit,
+        lp_amount,
+        can_compound,
+    )?;
+
+    let min_amounts = [min_amounts.0, min_amounts.1];
+    for (index, min_amount) in min_amounts.into_iter().enumerate() {
+        let amount = pool.amount_from_system_precision(withdraw_amount.amounts[index], index)?;
+        require!(amount >= min_amount, Error::Slippage);
+    }
 
     Withdraw {
         user: sender.clone(),
+        recipient: recipient.clone(),
         lp_amount,
         amounts: withdraw_amount.amounts.data,
         fees: withdraw_amount.fees.data,
     }
-    .publish(&env);
-
-    if !rewards.is_zero() {
-        RewardsClaimed {
-            user: sender,
-            rewards: rewards.data,
-        }
-        .publish(&env);
-    }
+    .publish(env);
+
+    publish_settled_rewards(env, sender, recipient, rewards, compounded_lp);
 
     Ok(())
 }
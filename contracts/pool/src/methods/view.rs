@@ -1,26 +1,35 @@
 use shared::{soroban_data::SimpleSorobanData, Error};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Vec};
 
-use crate::storage::user_deposit::UserDeposit;
-use crate::storage::{common::Token, pool::Pool};
+use crate::storage::{
+    common::{Direction, Token},
+    pool::Pool,
+    reward::{RewardSchedule, RewardTokens},
+    user_deposit::UserDeposit,
+};
 
-use super::internal::pool_view::WithdrawAmountView;
+use super::internal::pool_view::{SwapQuote, WithdrawAmountView};
 
 pub fn pending_reward(env: Env, user: Address) -> Result<(u128, u128), Error> {
     let user = UserDeposit::get(&env, user);
     let pool = Pool::get(&env)?;
 
-    let pending = pool.get_pending(&user);
+    let pending = pool.get_pending(&user)?;
 
     Ok((pending[0], pending[1]))
 }
 
+/// A is the value of the ramp at the current time
 pub fn get_pool(env: Env) -> Result<Pool, Error> {
-    Pool::get(&env)
+    get_current_pool(&env)
 }
 
 pub fn get_d(env: Env) -> Result<u128, Error> {
-    Ok(Pool::get(&env)?.total_lp_amount)
+    Ok(get_current_pool(&env)?.get_current_d())
+}
+
+pub fn get_virtual_price(env: Env) -> Result<u128, Error> {
+    Ok(get_current_pool(&env)?.get_virtual_price())
 }
 
 pub fn get_user_deposit(env: Env, user: Address) -> Result<UserDeposit, Error> {
@@ -28,20 +37,69 @@ pub fn get_user_deposit(env: Env, user: Address) -> Result<UserDeposit, Error> {
 }
 
 pub fn get_receive_amount(env: Env, input: u128, token_from: Token) -> Result<(u128, u128), Error> {
-    let receive_amount = Pool::get(&env)?.get_receive_amount(input, token_from)?;
+    let receive_amount = get_current_pool(&env)?.get_receive_amount(input, token_from)?;
     Ok((receive_amount.output, receive_amount.fee))
 }
 
 pub fn get_send_amount(env: Env, output: u128, token_to: Token) -> Result<(u128, u128), Error> {
-    Pool::get(&env)?.get_send_amount(output, token_to)
+    get_current_pool(&env)?.get_send_amount(output, token_to)
+}
+
+pub fn quote_curve_swap(env: Env, input: u128, direction: Direction) -> Result<SwapQuote, Error> {
+    get_current_pool(&env)?.quote_curve_swap(input, direction)
+}
+
+pub fn quote_curve_swap_exact_out(
+    env: Env,
+    output: u128,
+    direction: Direction,
+) -> Result<SwapQuote, Error> {
+    get_current_pool(&env)?.quote_curve_swap_exact_out(output, direction)
 }
 
 pub fn get_withdraw_amount(env: Env, lp_amount: u128) -> Result<WithdrawAmountView, Error> {
-    Ok(Pool::get(&env)?.get_withdraw_amount(lp_amount)?.into())
+    Ok(get_current_pool(&env)?
+        .get_withdraw_amount(lp_amount)?
+        .into())
 }
 
 pub fn get_deposit_amount(env: Env, amounts: (u128, u128)) -> Result<u128, Error> {
-    let deposit_amount = Pool::get(&env)?.get_deposit_amount(amounts.into())?;
+    let deposit_amount = get_current_pool(&env)?.get_deposit_amount(amounts.into())?;
 
     Ok(deposit_amount.lp_amount)
 }
+
+pub fn get_reward_tokens(env: Env) -> Result<Vec<Address>, Error> {
+    Ok(RewardTokens::get_or_default(&env))
+}
+
+pub fn get_reward_schedule(env: Env, token: Address) -> Result<RewardSchedule, Error> {
+    RewardSchedule::get(&env, &token)
+}
+
+pub fn pending_reward_token(env: Env, user: Address, token: Address) -> Result<u128, Error> {
+    let user_deposit = UserDeposit::get(&env, user.clone());
+
+    Pool::get(&env)?.get_pending_reward_token(&env, &user, user_deposit.reward_shares(), &token)
+}
+
+pub fn pending_reward_tokens(env: Env, user: Address) -> Result<Vec<(Address, u128)>, Error> {
+    let pool = Pool::get(&env)?;
+    let user_deposit = UserDeposit::get(&env, user.clone());
+    let mut pending = Vec::new(&env);
+
+    for token in RewardTokens::get_or_default(&env) {
+        let amount =
+            pool.get_pending_reward_token(&env, &user, user_deposit.reward_shares(), &token)?;
+        pending.push_back((token, amount));
+    }
+
+    Ok(pending)
+}
+
+fn get_current_pool(env: &Env) -> Result<Pool, Error> {
+    let mut pool = Pool::get(env)?;
+    pool.sync_a(env);
+
+    Ok(pool)
+}
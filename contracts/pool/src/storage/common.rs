@@ -1,4 +1,4 @@
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Address};
 
 #[contracttype]
 #[derive(Debug, Clone, Copy)]
@@ -17,8 +17,21 @@ impl Direction {
     }
 }
 
+/// Operation of a `multicall`, executed by and for the sender
 #[contracttype]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
+pub enum PoolOp {
+    /// (amounts, min_lp_amount)
+    Deposit((u128, u128), u128),
+    /// (lp_amount, min_amounts)
+    Withdraw(u128, (u128, u128)),
+    /// (amount_in, receive_amount_min, direction)
+    Swap(u128, u128, Direction),
+    ClaimRewards,
+}
+
+#[contracttype]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(usize)]
 pub enum Token {
     A = 0,
@@ -43,3 +56,24 @@ impl Token {
         }
     }
 }
+
+/// Where the rate of a pool token comes from
+#[contracttype]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RateSource {
+    /// Worth 1:1
+    Fixed,
+    /// `get_rate` of the rate provider contract
+    Provider(Address),
+    /// LP of the base pool of a metapool, priced by the base pool's virtual price
+    BasePool(Address),
+}
+
+impl From<Option<Address>> for RateSource {
+    fn from(value: Option<Address>) -> Self {
+        match value {
+            Some(provider) => RateSource::Provider(provider),
+            None => RateSource::Fixed,
+        }
+    }
+}
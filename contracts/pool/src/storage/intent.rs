@@ -0,0 +1,69 @@
+use proc_macros::{extend_ttl_info, Persistent, SorobanData};
+use shared::{consts::DAY_IN_LEDGERS, soroban_data::SorobanData, Error};
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use super::common::Direction;
+
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+enum IntentKey {
+    /// owner => IntentSigner
+    Signer(Address),
+    /// (owner, nonce) => IntentFill
+    Fill(Address, u64),
+}
+
+/// Swap of the owner tokens signed off-chain, the signature is over its XDR encoding.
+/// It can be filled in parts at no worse price than `min_amount_out` for `amount_in`
+#[contracttype]
+#[derive(Debug, Clone)]
+pub struct SwapIntent {
+    /// Pool the intent is signed for
+    pub pool: Address,
+    /// Spends its tokens through an allowance to the pool and receives the output
+    pub owner: Address,
+    pub amount_in: u128,
+    pub min_amount_out: u128,
+    pub direction: Direction,
+    pub nonce: u64,
+    /// Last timestamp the intent can be filled at
+    pub expiry: u64,
+}
+
+/// ed25519 public key signing the swap intents of an owner
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct IntentSigner(pub BytesN<32>);
+
+impl IntentSigner {
+    pub fn get(env: &Env, owner: &Address) -> Result<BytesN<32>, Error> {
+        IntentSigner::get_by_key(env, &IntentKey::Signer(owner.clone()))
+            .map(|IntentSigner(public_key)| public_key)
+            .map_err(|_| Error::Unauthorized)
+    }
+
+    pub fn save(&self, env: &Env, owner: &Address) {
+        self.save_by_key(env, &IntentKey::Signer(owner.clone()));
+    }
+}
+
+/// Input amount filled of an intent, `u128::MAX` once cancelled
+#[contracttype]
+#[derive(Debug, Clone, Default, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct IntentFill(pub u128);
+
+impl IntentFill {
+    pub fn get(env: &Env, owner: &Address, nonce: u64) -> u128 {
+        IntentFill::get_by_key(env, &IntentKey::Fill(owner.clone(), nonce))
+            .map(|IntentFill(filled)| filled)
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, env: &Env, owner: &Address, nonce: u64) {
+        self.save_by_key(env, &IntentKey::Fill(owner.clone(), nonce));
+    }
+}
@@ -1,4 +1,7 @@
 pub mod common;
 pub mod double_values;
+pub mod intent;
+pub mod operator;
 pub mod pool;
+pub mod reward;
 pub mod user_deposit;
@@ -0,0 +1,41 @@
+use proc_macros::{extend_ttl_info, Persistent, SorobanData};
+use shared::{consts::DAY_IN_LEDGERS, soroban_data::SorobanData, Error};
+use soroban_sdk::{contracttype, Address, Env};
+
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+enum OperatorKey {
+    /// user => Operator
+    Operator(Address),
+}
+
+/// Address allowed to claim the rewards of a user to any recipient
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct Operator(pub Address);
+
+impl Operator {
+    pub fn get(env: &Env, user: &Address) -> Result<Address, Error> {
+        Operator::get_by_key(env, &OperatorKey::Operator(user.clone()))
+            .map(|Operator(operator)| operator)
+            .map_err(|_| Error::Unauthorized)
+    }
+
+    pub fn set(env: &Env, user: &Address, operator: Option<Address>) {
+        let key = OperatorKey::Operator(user.clone());
+
+        match operator {
+            Some(operator) => Operator(operator).save_by_key(env, &key),
+            None => Operator::remove_by_key(env, &key),
+        }
+    }
+
+    pub fn require_auth(env: &Env, user: &Address) -> Result<(), Error> {
+        Operator::get(env, user)?.require_auth();
+
+        Ok(())
+    }
+}
@@ -1,12 +1,13 @@
 use proc_macros::{extend_ttl_info_instance, Instance, SorobanData, SorobanSimpleData, SymbolKey};
+use shared::{utils::safe_cast, Error};
 use soroban_sdk::{
     contracttype,
     token::{self, TokenClient},
-    Address, Env,
+    vec, Address, Env, IntoVal, Symbol,
 };
 
 use super::{
-    common::Token,
+    common::{RateSource, Token},
     double_values::{DoubleAddress, DoubleU128, DoubleU32},
 };
 
@@ -15,42 +16,142 @@ use super::{
 #[extend_ttl_info_instance]
 pub struct Pool {
     pub a: u128,
+    /// A ramp, `a` moves from `initial_a` at `ramp_start` to `future_a` at `ramp_end`
+    pub initial_a: u128,
+    pub future_a: u128,
+    pub ramp_start: u64,
+    pub ramp_end: u64,
 
     pub fee_share_bp: u128,
     pub admin_fee_share_bp: u128,
     pub total_lp_amount: u128,
+    /// Invariant of the balances at `a`, equal to the LP amount until A is ramped
+    pub d: u128,
+    /// LP weighted by the lock boosts, the rewards are shared by it
+    pub total_reward_shares: u128,
 
     pub tokens: DoubleAddress,
     pub tokens_decimals: DoubleU32,
+    /// Decimals the balances and the LP amount are kept in
+    pub system_precision: u32,
+    /// Cap on the sum of the balances (system precision)
+    pub max_token_balance: u128,
     pub token_balances: DoubleU128,
     pub acc_rewards_per_share_p: DoubleU128,
+    /// Rewards added while there were no reward shares, shared by the next ones
+    pub undistributed_rewards: DoubleU128,
     pub admin_fee_amount: DoubleU128,
+
+    /// Factory the pool was created by, owns the protocol fee
+    pub factory: Address,
+    /// Pushed by the factory, at most `Pool::MAX_PROTOCOL_FEE_SHARE_BP`
+    pub protocol_fee_share_bp: u128,
+    pub protocol_fee_amount: DoubleU128,
+
+    /// Swaps and deposits are disabled, withdrawals and claims are not
+    pub paused: bool,
+
+    pub rate_sources: (RateSource, RateSource),
+    /// Cached `get_rate` of the providers, `RATE_ONE` for fixed rates
+    pub rates: DoubleU128,
+    pub rates_updated_at: u64,
+    /// Cached rates older than this (seconds) are fetched again
+    pub rate_max_age: u64,
 }
 
 impl Pool {
+    #[allow(clippy::too_many_arguments)]
     pub fn from_init_params(
         a: u128,
         token_a: Address,
         token_b: Address,
         decimals: (u32, u32),
+        system_precision: u32,
+        max_token_balance: u128,
         fee_share_bp: u128,
         admin_fee_share_bp: u128,
+        factory: Address,
     ) -> Self {
         Pool {
             a,
+            initial_a: a,
+            future_a: a,
+            ramp_start: 0,
+            ramp_end: 0,
 
             fee_share_bp,
             admin_fee_share_bp,
             total_lp_amount: 0,
+            d: 0,
+            total_reward_shares: 0,
 
             tokens: DoubleAddress::from((token_a, token_b)),
             tokens_decimals: DoubleU32::from(decimals),
+            system_precision,
+            max_token_balance,
             token_balances: DoubleU128::default(),
             acc_rewards_per_share_p: DoubleU128::default(),
+            undistributed_rewards: DoubleU128::default(),
             admin_fee_amount: DoubleU128::default(),
+
+            factory,
+            protocol_fee_share_bp: 0,
+            protocol_fee_amount: DoubleU128::default(),
+
+            paused: false,
+
+            rate_sources: (RateSource::Fixed, RateSource::Fixed),
+            rates: DoubleU128::from((Pool::RATE_ONE, Pool::RATE_ONE)),
+            rates_updated_at: 0,
+            rate_max_age: 0,
+        }
+    }
+
+    #[inline]
+    pub fn rate_source(&self, index: usize) -> &RateSource {
+        match index {
+            0 => &self.rate_sources.0,
+            _ => &self.rate_sources.1,
+        }
+    }
+
+    /// Base pool of a metapool, its LP is the token B
+    #[inline]
+    pub fn base_pool(&self) -> Option<&Address> {
+        match &self.rate_sources.1 {
+            RateSource::BasePool(base_pool) => Some(base_pool),
+            _ => None,
         }
     }
 
+    /// Moves the LP of the base pool with `transfer_lp`, tokens with `transfer`
+    pub fn transfer(
+        &self,
+        env: &Env,
+        index: usize,
+        from: &Address,
+        to: &Address,
+        amount: u128,
+    ) -> Result<(), Error> {
+        match self.rate_source(index) {
+            RateSource::BasePool(base_pool) => env.invoke_contract::<()>(
+                base_pool,
+                &Symbol::new(env, "transfer_lp"),
+                vec![
+                    env,
+                    from.into_val(env),
+                    to.into_val(env),
+                    amount.into_val(env),
+                ],
+            ),
+            _ => self
+                .get_token_by_index(env, index)
+                .transfer(from, to, &safe_cast(amount)?),
+        }
+
+        Ok(())
+    }
+
     #[inline]
     pub fn get_token_by_index(&self, env: &Env, index: usize) -> TokenClient<'_> {
         token::Client::new(env, &self.tokens[index])
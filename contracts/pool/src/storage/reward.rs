@@ -0,0 +1,126 @@
+use ethnum::U256;
+use proc_macros::{
+    extend_ttl_info, extend_ttl_info_instance, Instance, Persistent, SorobanData,
+    SorobanSimpleData, SymbolKey,
+};
+use shared::{
+    consts::DAY_IN_LEDGERS,
+    soroban_data::{SimpleSorobanData, SorobanData},
+    utils::safe_cast,
+    Error,
+};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use super::pool::Pool;
+
+const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+enum RewardKey {
+    /// reward token => RewardSchedule
+    Schedule(Address),
+    /// (user, reward token) => UserReward
+    User(Address, Address),
+}
+
+/// External reward tokens streamed to the LP, in the order they were added
+#[contracttype]
+#[derive(Debug, Clone, SorobanData, SorobanSimpleData, SymbolKey, Instance)]
+#[extend_ttl_info_instance]
+pub struct RewardTokens(pub Vec<Address>);
+
+impl RewardTokens {
+    pub const MAX_REWARD_TOKENS: u32 = 5;
+
+    pub fn get_or_default(env: &Env) -> Vec<Address> {
+        RewardTokens::get(env)
+            .map(|RewardTokens(tokens)| tokens)
+            .unwrap_or_else(|_| Vec::new(env))
+    }
+}
+
+#[contracttype]
+#[derive(Debug, Clone, Default, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct RewardSchedule {
+    /// Token amount streamed per second until `period_finish`
+    pub rate: u128,
+    pub period_finish: u64,
+    pub last_update: u64,
+    pub acc_reward_per_share_p: u128,
+    /// Streamed while there were no reward shares, accrued to the next ones
+    pub undistributed: u128,
+}
+
+impl RewardSchedule {
+    pub fn get(env: &Env, token: &Address) -> Result<RewardSchedule, Error> {
+        RewardSchedule::get_by_key(env, &RewardKey::Schedule(token.clone()))
+            .map_err(|_| Error::NotFound)
+    }
+
+    pub fn save(&self, env: &Env, token: &Address) {
+        self.save_by_key(env, &RewardKey::Schedule(token.clone()));
+    }
+
+    /// Streams the rewards since the last update to `total_reward_shares`,
+    /// the rewards streamed while there is no LP are kept for the next LP
+    pub fn update(&mut self, now: u64, total_reward_shares: u128) -> Result<(), Error> {
+        let until = now.min(self.period_finish);
+
+        if until > self.last_update {
+            let reward_amount = self
+                .rate
+                .checked_mul((until - self.last_update) as u128)
+                .ok_or(Error::PoolOverflow)?;
+            self.undistributed = self
+                .undistributed
+                .checked_add(reward_amount)
+                .ok_or(Error::PoolOverflow)?;
+        }
+
+        if self.undistributed > 0 && total_reward_shares > 0 {
+            let reward_per_share_p =
+                (U256::new(self.undistributed) << Pool::P) / U256::new(total_reward_shares);
+            self.acc_reward_per_share_p = safe_cast::<U256, u128>(reward_per_share_p)
+                .ok()
+                .and_then(|value| self.acc_reward_per_share_p.checked_add(value))
+                .ok_or(Error::PoolOverflow)?;
+            self.undistributed = 0;
+        }
+
+        self.last_update = now;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn get_reward_debt(&self, shares: u128) -> Result<u128, Error> {
+        safe_cast((U256::new(shares) * U256::new(self.acc_reward_per_share_p)) >> Pool::P)
+            .map_err(|_| Error::PoolOverflow)
+    }
+}
+
+#[contracttype]
+#[derive(Debug, Clone, Default, SorobanData, Persistent)]
+#[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
+pub struct UserReward {
+    pub reward_debt: u128,
+    /// Accrued by the LP before its last change, not claimed yet
+    pub unclaimed: u128,
+}
+
+impl UserReward {
+    pub fn get(env: &Env, user: &Address, token: &Address) -> UserReward {
+        UserReward::get_by_key(env, &RewardKey::User(user.clone(), token.clone()))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, env: &Env, user: &Address, token: &Address) {
+        self.save_by_key(env, &RewardKey::User(user.clone(), token.clone()));
+    }
+
+    pub fn get_pending(&self, schedule: &RewardSchedule, shares: u128) -> Result<u128, Error> {
+        Ok(self.unclaimed + schedule.get_reward_debt(shares)? - self.reward_debt)
+    }
+}
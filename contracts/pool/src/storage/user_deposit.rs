@@ -3,7 +3,7 @@ use shared::consts::DAY_IN_LEDGERS;
 use shared::soroban_data::SorobanData;
 use soroban_sdk::{contracttype, Address, Env};
 
-use super::double_values::DoubleU128;
+use super::{double_values::DoubleU128, pool::Pool};
 
 const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
@@ -12,8 +12,15 @@ const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
 #[derive(Clone, Debug, SorobanData, Default, Persistent)]
 #[extend_ttl_info(BUMP_AMOUNT, LIFETIME_THRESHOLD)]
 pub struct UserDeposit {
+    /// Including the locked LP
     pub lp_amount: u128,
     pub reward_debts: DoubleU128,
+    pub locked_amount: u128,
+    pub unlock_time: u64,
+    /// Reward weight of the locked LP, `Pool::BP` is 1x
+    pub lock_weight_bp: u128,
+    /// Rewards settled on deposit and withdraw are deposited back as liquidity
+    pub auto_compound: bool,
 }
 
 impl UserDeposit {
@@ -24,4 +31,29 @@ impl UserDeposit {
     pub fn save(&self, env: &Env, address: Address) {
         self.save_by_key(env, &address);
     }
+
+    /// LP with the locked part weighted by its boost
+    pub fn reward_shares(&self) -> u128 {
+        let boost_bp = self.lock_weight_bp.saturating_sub(Pool::BP);
+
+        self.lp_amount + self.locked_amount * boost_bp / Pool::BP
+    }
+
+    /// LP that can be withdrawn or transferred at `now`
+    pub fn unlocked_amount(&self, now: u64) -> u128 {
+        if now < self.unlock_time {
+            self.lp_amount - self.locked_amount
+        } else {
+            self.lp_amount
+        }
+    }
+
+    /// Drops the lock and its boost once it is expired
+    pub fn release_expired_lock(&mut self, now: u64) {
+        if now >= self.unlock_time {
+            self.locked_amount = 0;
+            self.unlock_time = 0;
+            self.lock_weight_bp = 0;
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    fmt::{self, Display, Formatter},
     io::{stdout, Write},
     sync::{Arc, Mutex},
 };
@@ -41,9 +42,10 @@ impl RunResult {
     }
 }
 
-impl ToString for RunResult {
-    fn to_string(&self) -> String {
-        format!(
+impl Display for RunResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
             "💸 Swaps {}/{} | 📤 Deposits {}/{} | 📥 Withdrawals {}/{}",
             self.swaps.successful,
             self.swaps.total,
@@ -114,7 +116,7 @@ fn main() {
         let mut current_run = successful_runs.lock().unwrap();
         *current_run += 1;
 
-        let log = format!("{current_run}/{}. {}", runs.len(), &run_result.to_string());
+        let log = format!("{current_run}/{}. {}", runs.len(), run_result);
 
         stdout().flush().expect("Unable to flush stdout");
         print!("\r{log}    ");
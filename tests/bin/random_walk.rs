@@ -1,3 +1,5 @@
+use std::fmt::{self, Display, Formatter};
+
 use clap::Parser;
 use clap_derive::Parser;
 use csv::Writer;
@@ -31,9 +33,10 @@ impl RunResult {
     }
 }
 
-impl ToString for RunResult {
-    fn to_string(&self) -> String {
-        format!(
+impl Display for RunResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
             "💸 Swaps {}/{} | 📤 Deposits {}/{} | 📥 Withdrawals {}/{}",
             self.swaps.successful,
             self.swaps.total,
@@ -91,5 +94,5 @@ fn main() {
     }
 
     wtr.flush().unwrap();
-    println!("{}", run_result.to_string());
+    println!("{}", run_result);
 }
@@ -6,3 +6,7 @@ pub mod factory {
     #![allow(clippy::too_many_arguments)]
     soroban_sdk::contractimport!(file = "../target/wasm32-unknown-unknown/release/factory.wasm");
 }
+
+pub mod orders {
+    soroban_sdk::contractimport!(file = "../target/wasm32-unknown-unknown/release/orders.wasm");
+}
@@ -1,11 +1,18 @@
-use crate::utils::TestingEnv;
+use soroban_sdk::{vec, Address};
+
+use crate::{
+    contracts::{
+        factory::{PairRemoved, PairReplaced},
+        pool,
+    },
+    utils::{float_to_uint, float_to_uint_sp, get_latest_event, Pool, TestingEnv},
+};
 
 #[test]
 #[should_panic = "Context(InvalidAction)"]
 fn add_new_pair_no_auth() {
     let testing_env = TestingEnv::default();
-    let (yellow_token, duck_token) =
-        TestingEnv::generate_token_pair(&testing_env.env, testing_env.admin.as_ref());
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
 
     testing_env.clear_mock_auth().factory.create_pair(
         testing_env.admin.as_ref(),
@@ -35,8 +42,7 @@ fn identical_addresses() {
 #[should_panic = "DexContract(InvalidArg)"]
 fn invalid_fee_share() {
     let testing_env = TestingEnv::default();
-    let (yellow, duck) =
-        TestingEnv::generate_token_pair(&testing_env.env, testing_env.admin.as_ref());
+    let (yellow, duck) = testing_env.generate_listed_token_pair();
 
     testing_env.factory.create_pair(
         testing_env.admin.as_ref(),
@@ -52,8 +58,7 @@ fn invalid_fee_share() {
 #[should_panic = "DexContract(InvalidArg)"]
 fn invalid_admin_fee_share() {
     let testing_env = TestingEnv::default();
-    let (yellow_token, duck_token) =
-        TestingEnv::generate_token_pair(&testing_env.env, testing_env.admin.as_ref());
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
 
     testing_env.factory.create_pair(
         testing_env.admin.as_ref(),
@@ -97,8 +102,7 @@ fn pair_exist_reverse() {
 #[test]
 fn add_new_pair() {
     let testing_env = TestingEnv::default();
-    let (yellow_token, duck_token) =
-        TestingEnv::generate_token_pair(&testing_env.env, testing_env.admin.as_ref());
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
 
     let deployed_pool = testing_env.factory.create_pair(
         testing_env.admin.as_ref(),
@@ -129,3 +133,858 @@ fn get_pool() {
     let pool = testing_env.factory.pool(&yusd_token.id, &yaro_token.id);
     assert_eq!(pool, testing_env.pool.id);
 }
+
+#[test]
+fn create_pair_multiple_tiers() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref yaro_token,
+        ref yusd_token,
+        ..
+    } = testing_env;
+
+    let tier_pool = testing_env.factory.create_pair_with_tier(
+        testing_env.admin.as_ref(),
+        100,
+        &yusd_token.id,
+        &yaro_token.id,
+        4,
+        1000,
+        1,
+    );
+
+    assert_ne!(tier_pool, testing_env.pool.id);
+    assert_eq!(
+        testing_env.factory.pool(&yaro_token.id, &yusd_token.id),
+        testing_env.pool.id
+    );
+    assert_eq!(
+        testing_env
+            .factory
+            .pool_by_tier(&yaro_token.id, &yusd_token.id, 1),
+        tier_pool
+    );
+    Pool::new(&testing_env.env, tier_pool.clone()).assert_initialization(100, 4, 1000);
+
+    let tiers = testing_env
+        .factory
+        .pools_for_pair(&yusd_token.id, &yaro_token.id);
+    assert_eq!(tiers.len(), 2);
+
+    let tier = tiers.get(1).unwrap();
+    assert_eq!(tier.tier, 1);
+    assert_eq!(tier.pool, tier_pool);
+    assert_eq!(tier.params.a, 100);
+    assert_eq!(tier.params.fee_share_bp, 4);
+    assert_eq!(tier.params.admin_fee_share_bp, 1000);
+}
+
+#[test]
+#[should_panic = "DexContract(PairExist)"]
+fn pair_tier_exist() {
+    let testing_env = TestingEnv::default();
+
+    for _ in 0..2 {
+        testing_env.factory.create_pair_with_tier(
+            testing_env.admin.as_ref(),
+            10,
+            &testing_env.yaro_token.id,
+            &testing_env.yusd_token.id,
+            10,
+            10,
+            1,
+        );
+    }
+}
+
+#[test]
+#[should_panic = "DexContract(NotFound)"]
+fn pool_by_tier_not_found() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .factory
+        .pool_by_tier(&testing_env.yaro_token.id, &testing_env.yusd_token.id, 1);
+}
+
+#[test]
+fn pools_page() {
+    let testing_env = TestingEnv::default();
+    let mut pools = std::vec![testing_env.pool.id.clone()];
+
+    for _ in 0..3 {
+        let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+        pools.push(testing_env.factory.create_pair(
+            testing_env.admin.as_ref(),
+            10,
+            &yellow_token.id,
+            &duck_token.id,
+            10,
+            10,
+        ));
+    }
+
+    assert_eq!(testing_env.factory.pools_count(), 4);
+
+    let page = testing_env.factory.pools_page(1, 2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().pool, pools[1]);
+    assert_eq!(page.get(1).unwrap().pool, pools[2]);
+
+    let page = testing_env.factory.pools_page(3, 10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().pool, pools[3]);
+
+    assert_eq!(testing_env.factory.pools_page(4, 10).len(), 0);
+
+    let record = testing_env.factory.pool_by_index(0);
+    assert_eq!(record.pool, testing_env.pool.id);
+    assert_eq!(record.tier, 0);
+
+    // The deprecated full listing stays available
+    let all_pools = testing_env.factory.pools();
+    assert_eq!(all_pools.len(), 4);
+    for pool in pools {
+        assert!(all_pools.contains_key(pool));
+    }
+}
+
+#[test]
+#[should_panic = "DexContract(NotFound)"]
+fn pool_by_index_not_found() {
+    let testing_env = TestingEnv::default();
+
+    testing_env.factory.pool_by_index(1);
+}
+
+#[test]
+fn predict_pool_address() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+
+    let predicted_default = testing_env.factory.predict_pool_address(
+        testing_env.admin.as_ref(),
+        &duck_token.id,
+        &yellow_token.id,
+        0,
+    );
+    let predicted_tier = testing_env.factory.predict_pool_address(
+        testing_env.admin.as_ref(),
+        &yellow_token.id,
+        &duck_token.id,
+        1,
+    );
+    assert_ne!(predicted_default, predicted_tier);
+
+    let deployed_default = testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        10,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+    );
+    let deployed_tier = testing_env.factory.create_pair_with_tier(
+        testing_env.admin.as_ref(),
+        10,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+        1,
+    );
+
+    assert_eq!(deployed_default, predicted_default);
+    assert_eq!(deployed_tier, predicted_tier);
+}
+
+#[test]
+#[should_panic = "DexContract(PermissionlessDisabled)"]
+fn create_default_pair_permissionless_disabled() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+
+    testing_env.factory.create_default_pair(
+        testing_env.alice.as_ref(),
+        &yellow_token.id,
+        &duck_token.id,
+    );
+}
+
+#[test]
+fn create_default_pair() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+
+    testing_env.factory.set_permissionless(true);
+    testing_env.factory.set_default_pool_params(50, 4, 1000);
+
+    let deployed_pool = testing_env.factory.create_default_pair(
+        testing_env.alice.as_ref(),
+        &yellow_token.id,
+        &duck_token.id,
+    );
+
+    assert_eq!(
+        testing_env.factory.pool(&yellow_token.id, &duck_token.id),
+        deployed_pool
+    );
+    Pool::new(&testing_env.env, deployed_pool).assert_initialization(50, 4, 1000);
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn create_pair_out_of_range() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+
+    testing_env
+        .factory
+        .set_pool_params_ranges((10, 200), (1, 100), (0, 5000));
+
+    testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        500,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn set_pool_params_ranges_excluding_defaults() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .factory
+        .set_pool_params_ranges((100, 200), (1, 100), (0, 5000));
+}
+
+#[test]
+#[should_panic = "Context(InvalidAction)"]
+fn set_permissionless_no_auth() {
+    let testing_env = TestingEnv::default();
+    testing_env
+        .clear_mock_auth()
+        .factory
+        .set_permissionless(true);
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn set_protocol_fee_invalid() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .factory
+        .set_protocol_fee(5001, testing_env.admin.as_ref());
+}
+
+#[test]
+fn create_pair_with_protocol_fee() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+    testing_env
+        .factory
+        .set_protocol_fee(2000, testing_env.admin.as_ref());
+
+    let pool = testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        10,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+    );
+
+    let pool_info = Pool::new(&testing_env.env, pool).client.get_pool();
+    assert_eq!(pool_info.protocol_fee_share_bp, 2000);
+}
+
+#[test]
+#[should_panic = "Context(InvalidAction)"]
+fn sync_pools_protocol_fee_no_auth() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .clear_mock_auth()
+        .factory
+        .sync_pools_protocol_fee(&vec![&testing_env.env, testing_env.pool.id.clone()]);
+}
+
+#[test]
+#[should_panic = "Context(InvalidAction)"]
+fn set_protocol_fee_no_auth() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .clear_mock_auth()
+        .factory
+        .set_protocol_fee(1000, testing_env.admin.as_ref());
+}
+
+#[test]
+fn set_pools_fee_share() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+    let second_pool = testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        10,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+    );
+    let second_pool = Pool::new(&testing_env.env, second_pool);
+    let not_a_pool = testing_env.yaro_token.id.clone();
+
+    let results = testing_env.factory.set_pools_fee_share(
+        &vec![
+            &testing_env.env,
+            testing_env.pool.id.clone(),
+            not_a_pool.clone(),
+            second_pool.id.clone(),
+        ],
+        25,
+    );
+
+    assert_eq!(
+        results,
+        vec![
+            &testing_env.env,
+            (testing_env.pool.id.clone(), true),
+            (not_a_pool, false),
+            (second_pool.id.clone(), true),
+        ]
+    );
+    assert_eq!(testing_env.pool.fee_share_bp(), 25);
+    assert_eq!(second_pool.fee_share_bp(), 25);
+
+    testing_env
+        .factory
+        .set_pools_admin_fee_share(&vec![&testing_env.env, second_pool.id.clone()], 500);
+    assert_eq!(second_pool.admin_fee_share_bp(), 500);
+    assert_eq!(testing_env.pool.admin_fee_share_bp(), 0);
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn set_pools_fee_share_out_of_range() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .factory
+        .set_pools_fee_share(&vec![&testing_env.env, testing_env.pool.id.clone()], 10_000);
+}
+
+#[test]
+#[should_panic = "Context(InvalidAction)"]
+fn pause_pools_no_auth() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .clear_mock_auth()
+        .factory
+        .pause_pools(&vec![&testing_env.env, testing_env.pool.id.clone()]);
+}
+
+#[test]
+fn create_pair_with_precision() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+
+    testing_env.factory.set_pool_precision(7, 2u128.pow(40));
+    let pool = testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        20,
+        &yellow_token.id,
+        &duck_token.id,
+        15,
+        2000,
+    );
+
+    let pool_info = Pool::new(&testing_env.env, pool).client.get_pool();
+    assert_eq!(pool_info.system_precision, 7);
+    assert_eq!(pool_info.max_token_balance, 2u128.pow(40));
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn create_pair_precision_overflow() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+
+    // A * max_balance³ does not fit into u128
+    testing_env.factory.set_pool_precision(7, 2u128.pow(44));
+    testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        20,
+        &yellow_token.id,
+        &duck_token.id,
+        15,
+        2000,
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(TokenNotListed)"]
+fn create_pair_token_not_listed() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) =
+        TestingEnv::generate_token_pair(&testing_env.env, testing_env.admin.as_ref());
+    testing_env.factory.list_token(&yellow_token.id, 7, false);
+
+    testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        10,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(TokenDecimalsMismatch)"]
+fn list_token_decimals_mismatch() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, _) =
+        TestingEnv::generate_token_pair(&testing_env.env, testing_env.admin.as_ref());
+
+    testing_env.factory.list_token(&yellow_token.id, 6, false);
+}
+
+#[test]
+#[should_panic = "Context(InvalidAction)"]
+fn list_token_no_auth() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, _) =
+        TestingEnv::generate_token_pair(&testing_env.env, testing_env.admin.as_ref());
+
+    testing_env
+        .clear_mock_auth()
+        .factory
+        .list_token(&yellow_token.id, 7, false);
+}
+
+#[test]
+fn block_token() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+    let create_pair = |token_a: &Address, token_b: &Address, tier: u32| {
+        testing_env.factory.create_pair_with_tier(
+            testing_env.admin.as_ref(),
+            10,
+            token_a,
+            token_b,
+            10,
+            10,
+            tier,
+        )
+    };
+
+    let yellow_duck = create_pair(&yellow_token.id, &duck_token.id, 0);
+    let yellow_duck_tier = create_pair(&duck_token.id, &yellow_token.id, 1);
+    let yellow_yaro = create_pair(&yellow_token.id, &testing_env.yaro_token.id, 0);
+    let duck_yusd = create_pair(&duck_token.id, &testing_env.yusd_token.id, 0);
+    assert_eq!(testing_env.factory.pools_count(), 5);
+
+    let blocked = testing_env.factory.block_token(&yellow_token.id);
+    assert_eq!(
+        blocked,
+        vec![
+            &testing_env.env,
+            yellow_duck.clone(),
+            yellow_duck_tier,
+            yellow_yaro
+        ]
+    );
+    assert!(testing_env.factory.token_info(&yellow_token.id).blocked);
+    assert!(
+        Pool::new(&testing_env.env, yellow_duck)
+            .client
+            .get_pool()
+            .paused
+    );
+
+    assert_eq!(testing_env.factory.pools_count(), 2);
+    let pools = testing_env.factory.pools_page(0, 10);
+    assert_eq!(pools.get(0).unwrap().pool, testing_env.pool.id);
+    assert_eq!(pools.get(1).unwrap().pool, duck_yusd);
+    assert_eq!(testing_env.factory.pool_by_index(1).pool, duck_yusd);
+    assert_eq!(
+        testing_env
+            .factory
+            .pools_for_pair(&yellow_token.id, &duck_token.id)
+            .len(),
+        0
+    );
+
+    // Remaining pools of the counterpart token are kept
+    assert_eq!(
+        testing_env.factory.block_token(&duck_token.id),
+        vec![&testing_env.env, duck_yusd]
+    );
+    assert_eq!(testing_env.factory.pools_count(), 1);
+}
+
+#[test]
+fn block_token_metapool() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, _) = testing_env.generate_listed_token_pair();
+    let metapool = testing_env.factory.create_metapool(
+        testing_env.admin.as_ref(),
+        20,
+        &yellow_token.id,
+        (&testing_env.yusd_token.id, &testing_env.yaro_token.id),
+        0,
+        0,
+    );
+
+    // The metapool holds the LP of the blocked token pool
+    let blocked = testing_env.factory.block_token(&testing_env.yusd_token.id);
+
+    assert_eq!(
+        blocked,
+        vec![
+            &testing_env.env,
+            testing_env.pool.id.clone(),
+            metapool.clone()
+        ]
+    );
+    assert!(
+        Pool::new(&testing_env.env, metapool)
+            .client
+            .get_pool()
+            .paused
+    );
+    assert_eq!(testing_env.factory.pools_count(), 0);
+}
+
+#[test]
+#[should_panic = "DexContract(TokenBlocked)"]
+fn create_pair_token_blocked() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+    testing_env.factory.block_token(&yellow_token.id);
+
+    testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        10,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+    );
+}
+
+#[test]
+fn remove_pair() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref factory,
+        ref yaro_token,
+        ref yusd_token,
+        ref admin,
+        ..
+    } = testing_env;
+
+    let removed = factory.remove_pair(&yaro_token.id, &yusd_token.id, 0, true);
+    assert_eq!(removed, pool.id);
+
+    let event = get_latest_event::<PairRemoved>(env).expect("Expected PairRemoved");
+    assert_eq!(event.pool, pool.id);
+    assert_eq!(event.tier, 0);
+
+    assert_eq!(factory.pools_count(), 0);
+    assert_eq!(
+        factory.pools_for_pair(&yaro_token.id, &yusd_token.id).len(),
+        0
+    );
+
+    // Withdraw-only pool
+    assert!(pool.client.get_pool().paused);
+    pool.withdraw(admin, 1_000.0);
+}
+
+#[test]
+#[should_panic = "DexContract(NotFound)"]
+fn remove_pair_not_found() {
+    let testing_env = TestingEnv::default();
+
+    testing_env.factory.remove_pair(
+        &testing_env.yaro_token.id,
+        &testing_env.yusd_token.id,
+        1,
+        false,
+    );
+}
+
+#[test]
+#[should_panic = "Context(InvalidAction)"]
+fn remove_pair_no_auth() {
+    let testing_env = TestingEnv::default();
+
+    testing_env.clear_mock_auth().factory.remove_pair(
+        &testing_env.yaro_token.id,
+        &testing_env.yusd_token.id,
+        0,
+        false,
+    );
+}
+
+#[test]
+fn replace_pair() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref factory,
+        ref yaro_token,
+        ref yusd_token,
+        ref admin,
+        ..
+    } = testing_env;
+
+    let new_pool = factory.create_pair_with_tier(
+        admin.as_ref(),
+        50,
+        &yaro_token.id,
+        &yusd_token.id,
+        10,
+        10,
+        1,
+    );
+    factory.remove_pair(&yaro_token.id, &yusd_token.id, 1, false);
+
+    let replaced = factory.replace_pair(&yusd_token.id, &yaro_token.id, 0, &new_pool, false);
+    assert_eq!(replaced, pool.id);
+
+    let event = get_latest_event::<PairReplaced>(env).expect("Expected PairReplaced");
+    assert_eq!(event.old_pool, pool.id);
+    assert_eq!(event.new_pool, new_pool);
+
+    assert_eq!(factory.pool(&yaro_token.id, &yusd_token.id), new_pool);
+    assert_eq!(factory.pools_count(), 1);
+    assert_eq!(factory.pool_by_index(0).params.a, 50);
+    assert!(!pool.client.get_pool().paused);
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn replace_pair_other_tokens() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+    let other_pool = testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        10,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+    );
+
+    testing_env.factory.replace_pair(
+        &testing_env.yusd_token.id,
+        &testing_env.yaro_token.id,
+        0,
+        &other_pool,
+        false,
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn replace_pair_not_a_pool() {
+    let testing_env = TestingEnv::default();
+
+    testing_env.factory.replace_pair(
+        &testing_env.yusd_token.id,
+        &testing_env.yaro_token.id,
+        0,
+        &testing_env.native_token.id,
+        false,
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn replace_pair_not_deployed_by_factory() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref factory,
+        ref yaro_token,
+        ref yusd_token,
+        ref admin,
+        ..
+    } = testing_env;
+
+    // A genuine pool naming the factory, deployed by someone else
+    let other_pool = env.register_contract_wasm(None, pool::WASM);
+    pool::Client::new(env, &other_pool).initialize(
+        admin.as_ref(),
+        &50,
+        &yusd_token.id,
+        &yaro_token.id,
+        &10,
+        &10,
+        &factory.id,
+        &3,
+        &2u128.pow(40),
+    );
+
+    factory.replace_pair(&yusd_token.id, &yaro_token.id, 0, &other_pool, false);
+}
+
+#[test]
+#[should_panic = "DexContract(PairExist)"]
+fn replace_pair_registered_pool() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref factory,
+        ref yaro_token,
+        ref yusd_token,
+        ref admin,
+        ..
+    } = testing_env;
+
+    let tier_pool = factory.create_pair_with_tier(
+        admin.as_ref(),
+        50,
+        &yaro_token.id,
+        &yusd_token.id,
+        10,
+        10,
+        1,
+    );
+
+    factory.replace_pair(&yusd_token.id, &yaro_token.id, 0, &tier_pool, false);
+}
+
+#[test]
+fn create_pair_with_liquidity() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref factory,
+        ref alice,
+        ..
+    } = testing_env;
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+    yellow_token.airdrop(alice, 1_000.0);
+    duck_token.airdrop(alice, 1_000.0);
+
+    let pool = factory.create_pair_with_liquidity(
+        alice.as_ref(),
+        20,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+        (1_000.0, 1_000.0),
+        1_998.0,
+    );
+    let pool = Pool::new(env, pool);
+
+    let alice_lp = pool.user_deposit(alice).lp_amount;
+
+    assert_eq!(pool.total_lp() - alice_lp, 1_000);
+    assert_eq!(pool.client.get_user_deposit(&pool.id).lp_amount, 0);
+    assert_eq!(pool.total_lp(), float_to_uint_sp(2_000.0));
+    assert_eq!(yellow_token.balance_of(alice.as_ref()), 0);
+    assert_eq!(duck_token.balance_of(alice.as_ref()), 0);
+    assert_eq!(factory.pool(&yellow_token.id, &duck_token.id), pool.id);
+}
+
+#[test]
+fn create_pair_with_liquidity_locks_lp_in_pool_precision() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref factory,
+        ref alice,
+        ..
+    } = testing_env;
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+    yellow_token.airdrop(alice, 1_000.0);
+    duck_token.airdrop(alice, 1_000.0);
+
+    factory.set_pool_precision(7, 2u128.pow(40));
+    let pool = factory.create_pair_with_liquidity(
+        alice.as_ref(),
+        20,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+        (1_000.0, 1_000.0),
+        0.0,
+    );
+    let pool = Pool::new(env, pool);
+
+    // One LP unit in the system precision of the pool
+    let alice_lp = pool.user_deposit(alice).lp_amount;
+    assert_eq!(pool.total_lp() - alice_lp, 10u128.pow(7));
+    assert_eq!(pool.total_lp(), float_to_uint(2_000.0, 7));
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidFirstDeposit)"]
+fn create_pair_with_liquidity_too_small() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+    yellow_token.airdrop(&testing_env.alice, 1.0);
+    duck_token.airdrop(&testing_env.alice, 1.0);
+
+    testing_env.factory.create_pair_with_liquidity(
+        testing_env.alice.as_ref(),
+        20,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+        (0.4, 0.4),
+        0.0,
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidFirstDeposit)"]
+fn create_pair_with_liquidity_unbalanced() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+    yellow_token.airdrop(&testing_env.alice, 1_000.0);
+    duck_token.airdrop(&testing_env.alice, 1_000.0);
+
+    testing_env.factory.create_pair_with_liquidity(
+        testing_env.alice.as_ref(),
+        20,
+        &yellow_token.id,
+        &duck_token.id,
+        10,
+        10,
+        (1_000.0, 500.0),
+        0.0,
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(NotFound)"]
+fn create_metapool_base_not_found() {
+    let testing_env = TestingEnv::default();
+    let (yellow_token, duck_token) = testing_env.generate_listed_token_pair();
+
+    testing_env.factory.create_metapool(
+        testing_env.admin.as_ref(),
+        10,
+        &yellow_token.id,
+        (&duck_token.id, &testing_env.yusd_token.id),
+        10,
+        10,
+    );
+}
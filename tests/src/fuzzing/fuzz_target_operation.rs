@@ -1,3 +1,5 @@
+use std::fmt::{self, Display, Formatter};
+
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 use rand_derive2::RandGen;
@@ -90,8 +92,8 @@ pub enum FuzzTargetOperation {
     },
 }
 
-impl ToString for FuzzTargetOperation {
-    fn to_string(&self) -> String {
+impl Display for FuzzTargetOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             FuzzTargetOperation::Swap {
                 direction,
@@ -99,7 +101,8 @@ impl ToString for FuzzTargetOperation {
                 sender,
                 recipient,
             } => {
-                format!(
+                write!(
+                    f,
                     "**[Swap]** {} {:?}, from *{:?}* to *{:?}*",
                     amount.0, direction, sender, recipient
                 )
@@ -110,14 +113,15 @@ impl ToString for FuzzTargetOperation {
                 yusd_amount,
                 user,
             } => {
-                format!(
+                write!(
+                    f,
                     "**[Deposit]** *{:?}*, amounts: {} Yaro {} Yusd",
                     user, yaro_amount.0, yusd_amount.0,
                 )
             }
 
             FuzzTargetOperation::Withdraw { lp_amount, user } => {
-                format!("**[Withdraw]** *{:?}*, lp amount: {}", user, lp_amount.0)
+                write!(f, "**[Withdraw]** *{:?}*, lp amount: {}", user, lp_amount.0)
             }
         }
     }
@@ -153,11 +157,15 @@ impl FuzzTargetOperation {
                 yaro_amount,
                 yusd_amount,
                 user,
-            } => testing_env.pool.deposit_checked(
-                user.get_user(testing_env),
-                (yusd_amount.0, yaro_amount.0),
-                0.0,
-            ),
+            } => {
+                testing_env.pool.deposit_checked(
+                    user.get_user(testing_env),
+                    (yusd_amount.0, yaro_amount.0),
+                    0.0,
+                )?;
+
+                Ok(())
+            }
 
             FuzzTargetOperation::Withdraw { lp_amount, user } => testing_env
                 .pool
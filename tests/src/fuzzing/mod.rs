@@ -1 +1,2 @@
 pub mod fuzz_target_operation;
+pub mod share_price_operation;
@@ -0,0 +1,207 @@
+use std::fmt::{self, Display, Formatter};
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_derive2::RandGen;
+
+use crate::contracts::pool::{Direction, Withdraw};
+use crate::utils::{float_to_uint_sp, get_latest_event, CallResult, TestingEnv};
+
+use super::fuzz_target_operation::{Amount, SwapDirection};
+
+/// LP amount burned by the pool on the first deposit, one LP unit in `SYSTEM_PRECISION`
+pub const MIN_LOCKED_LP: u128 = 1000;
+
+const BP: u128 = 10_000;
+
+/// Rounding of a deposit and a withdraw in `SYSTEM_PRECISION` units
+const DUST_TOLERANCE: u128 = 4;
+
+const MAX_IMBALANCE_BP: u128 = 500;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DustAmount(pub f64);
+
+impl Distribution<DustAmount> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DustAmount {
+        DustAmount(rng.gen_range(1..10_000) as f64 / 1_000.0)
+    }
+}
+
+/// Alice plays the attacker trying to move the LP share price, Bob the victim depositing after her
+#[derive(Debug, RandGen)]
+pub enum SharePriceOperation {
+    AttackerDeposit {
+        amount: DustAmount,
+    },
+    /// Withdraws all of the attacker's LP except the smallest unit
+    AttackerWithdraw,
+    AttackerSwap {
+        direction: SwapDirection,
+        amount: Amount,
+    },
+    VictimDeposit {
+        amount: Amount,
+    },
+    VictimWithdraw,
+}
+
+impl Display for SharePriceOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SharePriceOperation::AttackerDeposit { amount } => {
+                write!(
+                    f,
+                    "**[AttackerDeposit]** amounts: {} Yaro {} Yusd",
+                    amount.0, amount.0
+                )
+            }
+            SharePriceOperation::AttackerWithdraw => write!(f, "**[AttackerWithdraw]**"),
+            SharePriceOperation::AttackerSwap { direction, amount } => {
+                write!(f, "**[AttackerSwap]** {} {:?}", amount.0, direction)
+            }
+            SharePriceOperation::VictimDeposit { amount } => {
+                write!(
+                    f,
+                    "**[VictimDeposit]** amounts: {} Yaro {} Yusd",
+                    amount.0, amount.0
+                )
+            }
+            SharePriceOperation::VictimWithdraw => write!(f, "**[VictimWithdraw]**"),
+        }
+    }
+}
+
+impl SharePriceOperation {
+    pub fn generate_run(len: usize) -> Vec<SharePriceOperation> {
+        let mut rng = rand::thread_rng();
+
+        (&mut rng).sample_iter(Standard).take(len).collect()
+    }
+
+    pub fn execute(&self, testing_env: &TestingEnv) -> CallResult {
+        let attacker = &testing_env.alice;
+        let victim = &testing_env.bob;
+
+        match self {
+            SharePriceOperation::AttackerDeposit { amount } => {
+                testing_env
+                    .pool
+                    .deposit_checked(attacker, (amount.0, amount.0), 0.0)?;
+
+                Ok(())
+            }
+
+            SharePriceOperation::AttackerWithdraw => {
+                let lp_amount = testing_env.pool.user_lp_amount_f64(attacker);
+
+                testing_env
+                    .pool
+                    .withdraw_checked(attacker, (lp_amount - 0.001).max(0.0))
+            }
+
+            SharePriceOperation::AttackerSwap { direction, amount } => {
+                let direction: Direction = (*direction).into();
+
+                testing_env
+                    .pool
+                    .swap_checked(attacker, attacker, amount.0, 0.0, direction)?;
+
+                Ok(())
+            }
+
+            SharePriceOperation::VictimDeposit { amount } => {
+                if !Self::is_near_peg(testing_env) {
+                    return Ok(());
+                }
+
+                let lp_before = testing_env.pool.user_deposit(victim).lp_amount;
+                // The first deposit into the empty pool pays for the burned LP
+                let locked_lp = match testing_env.pool.total_lp() {
+                    0 => MIN_LOCKED_LP,
+                    _ => 0,
+                };
+
+                testing_env
+                    .pool
+                    .deposit_checked(victim, (amount.0, amount.0), 0.0)?;
+
+                let lp_minted = testing_env.pool.user_deposit(victim).lp_amount - lp_before;
+                let withdraw_amount = testing_env.pool.client.get_withdraw_amount(&lp_minted);
+                let withdrawable = withdraw_amount.amounts.0 + withdraw_amount.amounts.1;
+
+                // Only the locked LP, the withdraw fee, the imbalance near the peg
+                // and the rounding dust are lost
+                let deposit = float_to_uint_sp(amount.0) * 2;
+                let fee_share_bp = testing_env.pool.client.get_pool().fee_share_bp;
+                let tolerance = deposit * fee_share_bp * 3 / BP + locked_lp + DUST_TOLERANCE;
+
+                assert!(lp_minted > 0, "Victim deposit minted no LP");
+                assert!(
+                    withdrawable + tolerance >= deposit,
+                    "Victim LP is worth less than the deposit: {withdrawable} of {deposit}"
+                );
+
+                Ok(())
+            }
+
+            SharePriceOperation::VictimWithdraw => {
+                if !Self::is_near_peg(testing_env) {
+                    return Ok(());
+                }
+
+                let lp_amount = testing_env.pool.user_lp_amount_f64(victim);
+
+                testing_env.pool.withdraw_checked(victim, lp_amount)
+            }
+        }
+    }
+
+    /// The victim waits while the pool balances are off by more than `MAX_IMBALANCE_BP`,
+    /// as an LP does not deposit into or withdraw from a pool pushed off the peg
+    fn is_near_peg(testing_env: &TestingEnv) -> bool {
+        let (yusd_balance, yaro_balance) = testing_env.pool.client.get_pool().token_balances.data;
+
+        yusd_balance.abs_diff(yaro_balance) * BP <= (yusd_balance + yaro_balance) * MAX_IMBALANCE_BP
+    }
+
+    /// Withdraw fees paid by the victim to the LP, the attacker may earn them as an LP
+    pub fn get_victim_fees(&self, testing_env: &TestingEnv, result: &CallResult) -> u128 {
+        if !matches!(self, SharePriceOperation::VictimWithdraw) || result.is_err() {
+            return 0;
+        }
+
+        get_latest_event::<Withdraw>(&testing_env.env)
+            .filter(|withdraw| withdraw.user == testing_env.bob.as_address())
+            .map_or(0, |withdraw| withdraw.fees.0 + withdraw.fees.1)
+    }
+
+    /// Once seeded, the burned LP stays in the supply without being owned by any deposit
+    pub fn assert_locked_lp(testing_env: &TestingEnv) {
+        let total_lp = testing_env.pool.total_lp();
+
+        if total_lp == 0 {
+            return;
+        }
+
+        let pool = &testing_env.pool;
+        let locked_lp = total_lp
+            - pool.user_deposit(&testing_env.alice).lp_amount
+            - pool.user_deposit(&testing_env.bob).lp_amount;
+
+        assert_eq!(locked_lp, MIN_LOCKED_LP, "Locked LP changed");
+        assert_eq!(
+            pool.client.get_user_deposit(&pool.id).lp_amount,
+            0,
+            "Locked LP owned by a deposit"
+        );
+    }
+
+    pub fn get_log_string(&self, result: &CallResult) -> String {
+        let log = self.to_string();
+        match result {
+            Ok(_) => log,
+            Err(err) => format!("{}, error: {:?}", log.as_str(), err),
+        }
+    }
+}
@@ -4,6 +4,9 @@ pub mod utils;
 #[cfg(test)]
 mod factory;
 
+#[cfg(test)]
+mod orders;
+
 #[cfg(test)]
 mod pool;
 
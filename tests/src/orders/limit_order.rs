@@ -0,0 +1,249 @@
+use crate::{
+    contracts::{
+        orders::{Direction, Token},
+        pool,
+    },
+    utils::{assert_rel_eq, float_to_uint, Orders, TestingEnv},
+};
+
+/// Ticks per sell token and orders per tick of the order book
+const MAX_TICKS: u32 = 20;
+const MAX_TICK_ORDERS: u32 = 20;
+/// Least price improvement on the worst tick to evict it
+const MIN_EVICTION_STEP_BP: u32 = 10;
+
+/// Orders contract of the default pool
+fn create_orders() -> (TestingEnv, Orders) {
+    let testing_env = TestingEnv::default();
+    let orders = Orders::create(&testing_env.env, &testing_env.pool.id);
+
+    (testing_env, orders)
+}
+
+/// Alice sells 1_000 yaro for 999 yusd
+fn place_order(testing_env: &TestingEnv, orders: &Orders) -> u64 {
+    orders.place_order(&testing_env.alice, Token::B, 9_990, 1_000.0)
+}
+
+#[test]
+fn swap_fills_order() {
+    let (testing_env, orders) = create_orders();
+    let id = place_order(&testing_env, &orders);
+    let TestingEnv {
+        ref pool, ref bob, ..
+    } = testing_env;
+    let balances_before = pool.client.get_pool().token_balances;
+
+    let received = orders.client.swap(
+        bob.as_ref(),
+        bob.as_ref(),
+        &float_to_uint(499.5, 7),
+        &float_to_uint(500.0, 7),
+        &Direction::A2B,
+    );
+
+    assert_eq!(received, float_to_uint(500.0, 7));
+    let order = orders.client.get_order(&id);
+    assert_eq!(order.amount, float_to_uint(500.0, 7));
+    assert_eq!(order.proceeds, float_to_uint(499.5, 7));
+    // The curve is not used
+    assert_eq!(pool.client.get_pool().token_balances, balances_before);
+}
+
+#[test]
+fn swap_fills_order_and_curve() {
+    let (testing_env, orders) = create_orders();
+    let id = place_order(&testing_env, &orders);
+    let TestingEnv {
+        ref alice,
+        ref bob,
+        ref yusd_token,
+        ..
+    } = testing_env;
+
+    let received = orders.client.swap(
+        bob.as_ref(),
+        bob.as_ref(),
+        &float_to_uint(2_000.0, 7),
+        &0,
+        &Direction::A2B,
+    );
+
+    // 1_000 from the order for 999 yusd, the remaining 1_001 yusd go through the curve
+    assert_rel_eq(received, float_to_uint(2_000.75, 7), float_to_uint(0.01, 7));
+    assert!(orders.client.get_order_book().ticks_b.is_empty());
+
+    let yusd_before = yusd_token.balance_of(alice.as_ref());
+    let proceeds = orders.claim_order(alice, id);
+    assert_eq!(proceeds, float_to_uint(999.0, 7));
+    assert_eq!(
+        yusd_token.balance_of(alice.as_ref()) - yusd_before,
+        proceeds
+    );
+    assert!(orders.client.try_get_order(&id).is_err());
+}
+
+#[test]
+fn order_worse_than_curve() {
+    let (testing_env, orders) = create_orders();
+    let TestingEnv {
+        ref alice, ref bob, ..
+    } = testing_env;
+    let id = orders.place_order(alice, Token::B, 10_100, 1_000.0);
+
+    orders.swap(bob, bob, 500.0, 0.0, Direction::A2B);
+
+    assert_eq!(
+        orders.client.get_order(&id).amount,
+        float_to_uint(1_000.0, 7)
+    );
+}
+
+#[test]
+fn pool_swap_skips_order() {
+    let (testing_env, orders) = create_orders();
+    let id = place_order(&testing_env, &orders);
+    let TestingEnv {
+        ref pool, ref bob, ..
+    } = testing_env;
+
+    pool.swap(bob, bob, 500.0, 0.0, pool::Direction::A2B);
+
+    assert_eq!(
+        orders.client.get_order(&id).amount,
+        float_to_uint(1_000.0, 7)
+    );
+}
+
+#[test]
+fn cancel_order() {
+    let (testing_env, orders) = create_orders();
+    let id = place_order(&testing_env, &orders);
+    let TestingEnv {
+        ref alice,
+        ref bob,
+        ref yaro_token,
+        ..
+    } = testing_env;
+
+    orders.swap(bob, bob, 499.5, 0.0, Direction::A2B);
+
+    let yaro_before = yaro_token.balance_of(alice.as_ref());
+    let (amount, proceeds) = orders.cancel_order(alice, id);
+
+    assert_eq!(amount, float_to_uint(500.0, 7));
+    assert_eq!(proceeds, float_to_uint(499.5, 7));
+    assert_eq!(yaro_token.balance_of(alice.as_ref()) - yaro_before, amount);
+    assert!(orders.client.get_order_book().ticks_b.is_empty());
+}
+
+#[test]
+#[should_panic = "DexContract(Unauthorized)"]
+fn cancel_order_not_owner() {
+    let (testing_env, orders) = create_orders();
+    let id = place_order(&testing_env, &orders);
+
+    orders.cancel_order(&testing_env.bob, id);
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn place_order_price_out_of_range() {
+    let (testing_env, orders) = create_orders();
+
+    orders.place_order(&testing_env.alice, Token::A, 11_000, 1_000.0);
+}
+
+#[test]
+#[should_panic = "DexContract(ZeroAmount)"]
+fn place_order_below_min_amount() {
+    let (testing_env, orders) = create_orders();
+
+    orders.place_order(&testing_env.alice, Token::B, 9_990, 99.0);
+}
+
+#[test]
+#[should_panic = "DexContract(Forbidden)"]
+fn place_order_tick_full() {
+    let (testing_env, orders) = create_orders();
+
+    for _ in 0..=MAX_TICK_ORDERS {
+        orders.place_order(&testing_env.alice, Token::B, 9_990, 100.0);
+    }
+}
+
+#[test]
+fn place_order_evicts_worst_tick() {
+    let (testing_env, orders) = create_orders();
+    let TestingEnv {
+        ref alice,
+        ref bob,
+        ref yaro_token,
+        ..
+    } = testing_env;
+
+    let worst_price_bp = 9_600 + MAX_TICKS - 1;
+    let mut worst_id = 0;
+    for price_bp in 9_600..=worst_price_bp {
+        worst_id = orders.place_order(alice, Token::B, price_bp, 100.0);
+    }
+
+    let alice_before = yaro_token.balance_of(alice.as_ref());
+    orders.place_order(bob, Token::B, 9_500, 200.0);
+
+    let ticks = orders.client.get_order_book().ticks_b;
+    assert_eq!(ticks.len(), MAX_TICKS);
+    assert_eq!(ticks.first().unwrap(), 9_500);
+    assert!(!ticks.contains(worst_price_bp));
+    assert!(orders.client.try_get_order(&worst_id).is_err());
+    assert_eq!(
+        yaro_token.balance_of(alice.as_ref()) - alice_before,
+        float_to_uint(100.0, 7)
+    );
+}
+
+/// Alice takes all ticks with 100 yaro orders from 9_600 up, 10 bp apart
+fn fill_ticks(testing_env: &TestingEnv, orders: &Orders) -> u32 {
+    let worst_price_bp = 9_600 + (MAX_TICKS - 1) * 10;
+
+    for price_bp in (9_600..=worst_price_bp).step_by(10) {
+        orders.place_order(&testing_env.alice, Token::B, price_bp, 100.0);
+    }
+
+    worst_price_bp
+}
+
+#[test]
+#[should_panic = "DexContract(Forbidden)"]
+fn place_order_evicts_below_min_step() {
+    let (testing_env, orders) = create_orders();
+    let worst_price_bp = fill_ticks(&testing_env, &orders);
+
+    orders.place_order(
+        &testing_env.bob,
+        Token::B,
+        worst_price_bp - MIN_EVICTION_STEP_BP + 1,
+        200.0,
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(Forbidden)"]
+fn place_order_evicts_larger_tick() {
+    let (testing_env, orders) = create_orders();
+    fill_ticks(&testing_env, &orders);
+
+    orders.place_order(&testing_env.bob, Token::B, 9_500, 100.0);
+}
+
+#[test]
+#[should_panic = "DexContract(Forbidden)"]
+fn place_order_worse_than_full_ticks() {
+    let (testing_env, orders) = create_orders();
+
+    for price_bp in 9_500..9_500 + MAX_TICKS {
+        orders.place_order(&testing_env.alice, Token::B, price_bp, 100.0);
+    }
+
+    orders.place_order(&testing_env.bob, Token::B, 10_000, 100.0);
+}
@@ -0,0 +1,229 @@
+use crate::{
+    contracts::{
+        orders::{Direction, Token},
+        pool,
+    },
+    utils::{assert_rel_eq, float_to_uint, Orders, TestingEnv},
+};
+
+/// Seconds of a long-term order interval
+const INTERVAL: u64 = 24 * 60 * 60;
+
+/// Orders contract of the default pool
+fn create_orders() -> (TestingEnv, Orders) {
+    let testing_env = TestingEnv::default();
+    let orders = Orders::create(&testing_env.env, &testing_env.pool.id);
+
+    (testing_env, orders)
+}
+
+/// Alice sells 10_000 yusd over the rest of the current day and the next one
+fn place_order(testing_env: &TestingEnv, orders: &Orders) -> u64 {
+    orders.place_long_term_order(&testing_env.alice, Token::A, 10_000.0, 2)
+}
+
+/// Curve output of the whole order amount
+fn order_receive_amount(testing_env: &TestingEnv, orders: &Orders, id: u64) -> (u128, u128) {
+    let order = orders.client.get_long_term_order(&id);
+    let now = testing_env.env.ledger().timestamp();
+    let sell_token = match order.sell_token {
+        Token::A => pool::Token::A,
+        Token::B => pool::Token::B,
+    };
+
+    testing_env.pool.client.get_receive_amount(
+        &(order.sale_rate * (order.expiry - now) as u128),
+        &sell_token,
+    )
+}
+
+#[test]
+fn place_long_term_order() {
+    let (testing_env, orders) = create_orders();
+    let TestingEnv {
+        ref alice,
+        ref yusd_token,
+        ..
+    } = testing_env;
+    let yusd_before = yusd_token.balance_of(alice.as_ref());
+
+    let id = place_order(&testing_env, &orders);
+
+    let now = testing_env.env.ledger().timestamp();
+    let order = orders.client.get_long_term_order(&id);
+    let expiry = (now / INTERVAL + 2) * INTERVAL;
+    assert_eq!(order.expiry, expiry);
+    assert_eq!(
+        order.sale_rate,
+        float_to_uint(10_000.0, 7) / (expiry - now) as u128
+    );
+
+    // Only the amount sold at the sale rate is escrowed
+    let escrowed = yusd_before - yusd_token.balance_of(alice.as_ref());
+    assert_eq!(escrowed, order.sale_rate * (expiry - now) as u128);
+
+    let twamm = orders.client.get_twamm();
+    assert_eq!(twamm.sale_rates.data, (order.sale_rate, 0));
+    assert_eq!(twamm.expiries.len(), 1);
+}
+
+#[test]
+fn swap_executes_long_term_orders() {
+    let (testing_env, orders) = create_orders();
+    place_order(&testing_env, &orders);
+    let TestingEnv {
+        ref pool, ref bob, ..
+    } = testing_env;
+    let balances_before = pool.client.get_pool().token_balances;
+
+    testing_env.skip_time(INTERVAL / 2);
+    orders.swap(bob, bob, 1.0, 0.0, Direction::A2B);
+
+    let twamm = orders.client.get_twamm();
+    assert_eq!(twamm.last_execution, testing_env.env.ledger().timestamp());
+    assert!(twamm.acc_proceeds_p.data.0 > 0);
+    assert!(pool.client.get_pool().token_balances.data.0 > balances_before.data.0 + 1);
+}
+
+#[test]
+fn withdraw_long_term_proceeds() {
+    let (testing_env, orders) = create_orders();
+    let id = place_order(&testing_env, &orders);
+    let TestingEnv {
+        ref alice,
+        ref yaro_token,
+        ..
+    } = testing_env;
+    let (expected, _) = order_receive_amount(&testing_env, &orders, id);
+
+    testing_env.skip_time(2 * INTERVAL);
+    let yaro_before = yaro_token.balance_of(alice.as_ref());
+    let proceeds = orders.withdraw_long_term_proceeds(alice, id);
+
+    // Sold on the curve at once, without interactions in between
+    assert_rel_eq(proceeds, expected, 1);
+    assert_eq!(
+        yaro_token.balance_of(alice.as_ref()) - yaro_before,
+        proceeds
+    );
+
+    // The expired order is removed with its expiry
+    let twamm = orders.client.get_twamm();
+    assert_eq!(twamm.sale_rates.data, (0, 0));
+    assert!(twamm.expiries.is_empty());
+    assert!(orders.client.try_get_long_term_order(&id).is_err());
+}
+
+#[test]
+fn withdraw_long_term_proceeds_twice() {
+    let (testing_env, orders) = create_orders();
+    let id = place_order(&testing_env, &orders);
+    let TestingEnv { ref alice, .. } = testing_env;
+    let (expected, _) = order_receive_amount(&testing_env, &orders, id);
+
+    testing_env.skip_time(INTERVAL / 2);
+    let first = orders.withdraw_long_term_proceeds(alice, id);
+    testing_env.skip_time(2 * INTERVAL);
+    let second = orders.withdraw_long_term_proceeds(alice, id);
+
+    assert!(first > 0);
+    assert_rel_eq(first + second, expected, float_to_uint(0.01, 7));
+}
+
+#[test]
+fn opposite_long_term_orders() {
+    let (testing_env, orders) = create_orders();
+    let TestingEnv {
+        ref alice, ref bob, ..
+    } = testing_env;
+    let id_a = orders.place_long_term_order(alice, Token::A, 10_000.0, 1);
+    let id_b = orders.place_long_term_order(bob, Token::B, 10_000.0, 1);
+
+    testing_env.skip_time(INTERVAL);
+
+    let proceeds_a = orders.withdraw_long_term_proceeds(alice, id_a);
+    let proceeds_b = orders.withdraw_long_term_proceeds(bob, id_b);
+    // The sales of yusd are swapped first, the ones of yaro get the price back
+    assert!(proceeds_b > proceeds_a);
+    assert_rel_eq(
+        proceeds_a + proceeds_b,
+        float_to_uint(20_000.0, 7),
+        float_to_uint(0.01, 7),
+    );
+}
+
+#[test]
+fn unexecutable_long_term_order_is_refunded() {
+    let (testing_env, orders) = create_orders();
+    let TestingEnv {
+        ref pool,
+        ref admin,
+        ref alice,
+        ref bob,
+        ref yusd_token,
+        ..
+    } = testing_env;
+    // Swapping the whole order would take the pool past its max token balance
+    yusd_token.airdrop(alice, 2_000_000_000.0);
+    let yusd_before = yusd_token.balance_of(alice.as_ref());
+    let id = orders.place_long_term_order(alice, Token::A, 2_000_000_000.0, 1);
+    let balances_before = pool.client.get_pool().token_balances;
+
+    testing_env.skip_time(INTERVAL);
+    pool.withdraw(admin, 1_000.0);
+    orders.swap(bob, bob, 1.0, 0.0, Direction::A2B);
+
+    // The sale is skipped, the swap of bob is the only one on the curve
+    let twamm = orders.client.get_twamm();
+    assert_eq!(twamm.last_execution, testing_env.env.ledger().timestamp());
+    assert_eq!(twamm.acc_proceeds_p.data, (0, 0));
+    assert!(pool.client.get_pool().token_balances.data.0 < balances_before.data.0);
+
+    let proceeds = orders.withdraw_long_term_proceeds(alice, id);
+    assert_eq!(proceeds, 0);
+    assert_eq!(yusd_token.balance_of(alice.as_ref()), yusd_before);
+    assert!(orders.client.try_get_long_term_order(&id).is_err());
+}
+
+#[test]
+fn cancel_long_term_order() {
+    let (testing_env, orders) = create_orders();
+    let id = place_order(&testing_env, &orders);
+    let TestingEnv {
+        ref alice,
+        ref yusd_token,
+        ..
+    } = testing_env;
+    let order = orders.client.get_long_term_order(&id);
+
+    testing_env.skip_time(INTERVAL / 2);
+    let yusd_before = yusd_token.balance_of(alice.as_ref());
+    let (amount, proceeds) = orders.cancel_long_term_order(alice, id);
+
+    let now = testing_env.env.ledger().timestamp();
+    assert_eq!(amount, order.sale_rate * (order.expiry - now) as u128);
+    assert_eq!(yusd_token.balance_of(alice.as_ref()) - yusd_before, amount);
+    assert!(proceeds > 0);
+
+    let twamm = orders.client.get_twamm();
+    assert_eq!(twamm.sale_rates.data, (0, 0));
+    assert!(twamm.expiries.is_empty());
+    assert!(orders.client.try_get_long_term_order(&id).is_err());
+}
+
+#[test]
+#[should_panic = "DexContract(Unauthorized)"]
+fn cancel_long_term_order_not_owner() {
+    let (testing_env, orders) = create_orders();
+    let id = place_order(&testing_env, &orders);
+
+    orders.cancel_long_term_order(&testing_env.bob, id);
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn place_long_term_order_too_long() {
+    let (testing_env, orders) = create_orders();
+
+    orders.place_long_term_order(&testing_env.alice, Token::A, 10_000.0, 31);
+}
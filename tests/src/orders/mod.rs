@@ -0,0 +1,2 @@
+pub mod limit_order;
+pub mod long_term_order;
@@ -1,4 +1,9 @@
-use crate::utils::{percentage_to_bp, TestingEnv};
+use soroban_sdk::vec;
+
+use crate::{
+    contracts::pool::Direction,
+    utils::{percentage_to_bp, TestingEnv},
+};
 
 #[test]
 fn set_admin_fee_share() {
@@ -45,3 +50,150 @@ fn set_fee_share_no_auth() {
     let testing_env = TestingEnv::default();
     testing_env.clear_mock_auth().pool.set_fee_share(1.0);
 }
+
+#[test]
+fn set_fee_share_by_pool_admin() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref bob,
+        ..
+    } = testing_env;
+
+    // The pool admin, not the factory admin, authorizes the pool params
+    pool.client.set_admin(&bob.as_address());
+    pool.set_fee_share(0.5);
+
+    assert_eq!(env.auths()[0].0, bob.as_address());
+    assert_eq!(pool.fee_share_bp(), percentage_to_bp(0.5));
+}
+
+#[test]
+#[should_panic = "Context(InvalidAction)"]
+fn set_fee_share_by_factory_no_auth() {
+    let testing_env = TestingEnv::default();
+    testing_env
+        .clear_mock_auth()
+        .pool
+        .set_fee_share_by_factory(1.0);
+}
+
+#[test]
+fn ramp_a() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref factory,
+        ref alice,
+        ..
+    } = testing_env;
+    let pools = vec![env, pool.id.clone()];
+    let day = 24 * 60 * 60;
+    let now = env.ledger().timestamp();
+
+    pool.swap(alice, alice, 10_000.0, 9_000.0, Direction::A2B);
+    let d_before = pool.client.get_pool().d;
+
+    let results = factory.ramp_pools_a(&pools, 60, now + 2 * day);
+    assert_eq!(results, vec![env, (pool.id.clone(), true)]);
+
+    testing_env.skip_time(day);
+    let pool_info = pool.client.get_pool();
+    assert_eq!(pool_info.a, 40);
+    // A higher A moves an imbalanced pool closer to the constant sum
+    assert!(pool_info.d > d_before);
+    assert_eq!(pool_info.d, pool.client.get_d());
+
+    pool.swap(alice, alice, 100.0, 98.0, Direction::B2A);
+    assert_eq!(pool.client.get_pool().a, 40);
+
+    factory.stop_pools_a_ramp(&pools);
+    testing_env.skip_time(2 * day);
+    assert_eq!(pool.client.get_pool().a, 40);
+}
+
+#[test]
+fn ramp_a_invalid() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref factory,
+        ..
+    } = testing_env;
+    let pools = vec![env, pool.id.clone()];
+    let now = env.ledger().timestamp();
+
+    // Shorter than a day and over 10x
+    let too_fast = factory.ramp_pools_a(&pools, 40, now + 60);
+    let too_far = factory.ramp_pools_a(&pools, 201, now + 7 * 24 * 60 * 60);
+
+    assert_eq!(too_fast, vec![env, (pool.id.clone(), false)]);
+    assert_eq!(too_far, vec![env, (pool.id.clone(), false)]);
+    assert_eq!(pool.client.get_pool().a, 20);
+}
+
+#[test]
+#[should_panic = "Context(InvalidAction)"]
+fn ramp_pools_a_no_auth() {
+    let testing_env = TestingEnv::default();
+    let now = testing_env.env.ledger().timestamp();
+
+    testing_env.clear_mock_auth().factory.ramp_pools_a(
+        &vec![&testing_env.env, testing_env.pool.id.clone()],
+        40,
+        now + 2 * 24 * 60 * 60,
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(Paused)"]
+fn paused_pool_swap() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref alice,
+        ..
+    } = testing_env;
+
+    testing_env.factory.pause_pools(&vec![env, pool.id.clone()]);
+    pool.swap(alice, alice, 100.0, 98.0, Direction::A2B);
+}
+
+#[test]
+#[should_panic = "DexContract(Paused)"]
+fn paused_pool_deposit() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref alice,
+        ..
+    } = testing_env;
+
+    testing_env.factory.pause_pools(&vec![env, pool.id.clone()]);
+    pool.deposit(alice, (100.0, 100.0), 0.0);
+}
+
+#[test]
+fn paused_pool_withdraw_and_unpause() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref admin,
+        ref alice,
+        ..
+    } = testing_env;
+
+    testing_env.factory.pause_pools(&vec![env, pool.id.clone()]);
+    pool.withdraw(admin, 1_000.0);
+
+    testing_env
+        .factory
+        .unpause_pools(&vec![env, pool.id.clone()]);
+    pool.swap(alice, alice, 100.0, 98.0, Direction::A2B);
+}
@@ -1,6 +1,8 @@
+use soroban_sdk::vec;
+
 use crate::{
-    contracts::pool::Direction,
-    utils::{TestingEnv, TestingEnvConfig, DOUBLE_ZERO},
+    contracts::pool::{Direction, RewardsClaimed, Token},
+    utils::{assert_rel_eq, get_latest_event, TestingEnv, TestingEnvConfig, User, DOUBLE_ZERO},
 };
 
 #[test]
@@ -41,6 +43,205 @@ fn claim_admin_fee() {
     testing_env.do_claim_admin_fee(DOUBLE_ZERO);
 }
 
+/// Pool with (yusd, yaro) admin fees from two swaps by alice
+fn accrue_admin_fee(testing_env: &TestingEnv) -> (u128, u128) {
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ..
+    } = testing_env;
+
+    pool.swap(alice, alice, 10_000.0, 0.0, Direction::B2A);
+    pool.swap(alice, alice, 10_000.0, 0.0, Direction::A2B);
+
+    pool.client.get_pool().admin_fee_amount.data
+}
+
+#[test]
+fn claim_admin_fee_to_partial() {
+    let testing_env = TestingEnv::create(
+        TestingEnvConfig::default()
+            .with_pool_fee_share(1.0)
+            .with_pool_admin_fee(1.0),
+    );
+    let (yusd_fee, yaro_fee) = accrue_admin_fee(&testing_env);
+    let TestingEnv {
+        ref pool,
+        ref bob,
+        ref yusd_token,
+        ref yaro_token,
+        ..
+    } = testing_env;
+
+    let bob_before = (
+        yusd_token.balance_of(bob.as_ref()),
+        yaro_token.balance_of(bob.as_ref()),
+    );
+    let claimed = pool.claim_admin_fee_to(bob, (yusd_fee / 2, yaro_fee), None, 0);
+
+    assert_eq!(claimed, (yusd_fee / 2, yaro_fee));
+    assert_eq!(
+        yusd_token.balance_of(bob.as_ref()) - bob_before.0,
+        yusd_fee / 2
+    );
+    assert_eq!(yaro_token.balance_of(bob.as_ref()) - bob_before.1, yaro_fee);
+    assert_eq!(
+        pool.client.get_pool().admin_fee_amount.data,
+        (yusd_fee - yusd_fee / 2, 0)
+    );
+}
+
+#[test]
+fn claim_admin_fee_to_converted() {
+    let testing_env = TestingEnv::create(
+        TestingEnvConfig::default()
+            .with_pool_fee_share(1.0)
+            .with_pool_admin_fee(1.0),
+    );
+    let (yusd_fee, yaro_fee) = accrue_admin_fee(&testing_env);
+    let TestingEnv {
+        ref pool,
+        ref bob,
+        ref yusd_token,
+        ref yaro_token,
+        ..
+    } = testing_env;
+
+    let bob_before = (
+        yusd_token.balance_of(bob.as_ref()),
+        yaro_token.balance_of(bob.as_ref()),
+    );
+    let (claimed_yusd, claimed_yaro) = pool.claim_admin_fee_to(
+        bob,
+        (yusd_fee, yaro_fee),
+        Some(Token::A),
+        yusd_fee + yaro_fee * 98 / 100,
+    );
+
+    assert_eq!(claimed_yaro, 0);
+    assert_rel_eq(claimed_yusd, yusd_fee + yaro_fee, yaro_fee / 50);
+    assert_eq!(
+        yusd_token.balance_of(bob.as_ref()) - bob_before.0,
+        claimed_yusd
+    );
+    assert_eq!(yaro_token.balance_of(bob.as_ref()), bob_before.1);
+    // Only the admin share of the conversion dust is left
+    assert!(pool.client.get_pool().admin_fee_amount.data.1 < yaro_fee / 100);
+}
+
+#[test]
+#[should_panic = "DexContract(NotEnoughAmount)"]
+fn claim_admin_fee_to_not_enough_amount() {
+    let testing_env = TestingEnv::create(
+        TestingEnvConfig::default()
+            .with_pool_fee_share(1.0)
+            .with_pool_admin_fee(1.0),
+    );
+    let (yusd_fee, _) = accrue_admin_fee(&testing_env);
+
+    testing_env
+        .pool
+        .claim_admin_fee_to(&testing_env.bob, (yusd_fee + 1, 0), None, 0);
+}
+
+#[test]
+#[should_panic(expected = "Context(InvalidAction)")]
+fn claim_protocol_fee_no_auth() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .clear_mock_auth()
+        .pool
+        .claim_protocol_fee(&testing_env.alice);
+}
+
+#[test]
+fn collect_protocol_fees() {
+    let testing_env = TestingEnv::create(
+        TestingEnvConfig::default()
+            .with_pool_fee_share(1.0)
+            .with_pool_admin_fee(1.0),
+    );
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref factory,
+        ref alice,
+        ref bob,
+        ref yusd_token,
+        ref yaro_token,
+        ..
+    } = testing_env;
+    let dao = User::generate(env, "dao");
+
+    // Half of every fee goes to the protocol, admin fee is taken from the rest
+    factory.set_protocol_fee(5000, dao.as_ref());
+    let synced = factory.sync_pools_protocol_fee(&vec![env, pool.id.clone()]);
+    assert_eq!(synced, vec![env, (pool.id.clone(), true)]);
+
+    pool.swap(alice, bob, 100.0, 98.0, Direction::B2A);
+    pool.swap(alice, bob, 100.0, 98.0, Direction::A2B);
+
+    let pool_info = pool.client.get_pool();
+    let (protocol_yusd_fee, protocol_yaro_fee) = pool_info.protocol_fee_amount.data;
+    let (admin_yusd_fee, admin_yaro_fee) = pool_info.admin_fee_amount.data;
+
+    assert_eq!(pool_info.protocol_fee_share_bp, 5000);
+    assert_rel_eq(admin_yusd_fee * 100, protocol_yusd_fee, 100);
+    assert_rel_eq(admin_yaro_fee * 100, protocol_yaro_fee, 100);
+
+    // A contract failing the claim does not abort the batch
+    let collected =
+        factory.collect_protocol_fees(&vec![env, yusd_token.id.clone(), pool.id.clone()]);
+    assert_eq!(
+        collected,
+        vec![
+            env,
+            (yusd_token.id.clone(), None),
+            (
+                pool.id.clone(),
+                Some((protocol_yusd_fee, protocol_yaro_fee))
+            )
+        ]
+    );
+    assert_eq!(yusd_token.balance_of(dao.as_ref()), protocol_yusd_fee);
+    assert_eq!(yaro_token.balance_of(dao.as_ref()), protocol_yaro_fee);
+
+    let collected = factory.collect_protocol_fees(&vec![env, pool.id.clone()]);
+    assert_eq!(collected, vec![env, (pool.id.clone(), Some((0, 0)))]);
+}
+
+#[test]
+fn protocol_fee_not_synced() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0));
+    let TestingEnv {
+        ref pool,
+        ref factory,
+        ref alice,
+        ref bob,
+        ..
+    } = testing_env;
+
+    // The pool keeps its share until the factory pushes the new one
+    factory.set_protocol_fee(5000, alice.as_ref());
+    pool.swap(alice, bob, 100.0, 98.0, Direction::A2B);
+
+    let pool_info = pool.client.get_pool();
+    assert_eq!(pool_info.protocol_fee_share_bp, 0);
+    assert_eq!(pool_info.protocol_fee_amount.data, (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Context(InvalidAction)")]
+fn set_protocol_fee_share_not_factory() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .clear_mock_auth()
+        .pool
+        .set_protocol_fee_share(1000);
+}
+
 #[test]
 fn claim_rewards() {
     let testing_env = TestingEnv::create(
@@ -116,3 +317,94 @@ fn get_rewards_after_second_claim() {
     pool.swap(bob, bob, 100.0, 98.0, Direction::B2A);
     testing_env.do_claim(alice, (yusd_expected_reward, 0.0));
 }
+
+#[test]
+fn claim_rewards_for() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0));
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref bob,
+        ref admin,
+        ref yaro_token,
+        ..
+    } = testing_env;
+
+    pool.deposit(alice, (2_000.0, 2_000.0), 0.0);
+    pool.swap(bob, bob, 100.0, 98.0, Direction::A2B);
+    pool.set_operator(alice, Some(bob));
+
+    let (_, pending) = pool.client.pending_reward(alice.as_ref());
+    let admin_before = yaro_token.balance_of(admin.as_ref());
+    pool.claim_rewards_for(alice, admin);
+
+    assert!(pending > 0);
+    assert_eq!(
+        yaro_token.balance_of(admin.as_ref()) - admin_before,
+        pending
+    );
+    assert_eq!(pool.client.pending_reward(alice.as_ref()), (0, 0));
+
+    let event = get_latest_event::<RewardsClaimed>(&testing_env.env).unwrap();
+    assert_eq!(event.user, alice.as_address());
+    assert_eq!(event.recipient, admin.as_address());
+    assert_eq!(event.rewards, (0, pending));
+}
+
+#[test]
+#[should_panic = "DexContract(Unauthorized)"]
+fn claim_rewards_for_no_operator() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .pool
+        .claim_rewards_for(&testing_env.alice, &testing_env.bob);
+}
+
+#[test]
+#[should_panic = "DexContract(Unauthorized)"]
+fn claim_rewards_for_removed_operator() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref bob,
+        ..
+    } = testing_env;
+
+    pool.set_operator(alice, Some(bob));
+    pool.set_operator(alice, None);
+    pool.claim_rewards_for(alice, bob);
+}
+
+#[test]
+fn rewards_without_lp_carried_to_next_deposit() {
+    let testing_env = TestingEnv::create(
+        TestingEnvConfig::default()
+            .with_pool_fee_share(1.0)
+            .with_admin_init_deposit(0.0),
+    );
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref bob,
+        ..
+    } = testing_env;
+
+    // Only the burned LP is left, it has no reward shares
+    pool.deposit(alice, (2_000.0, 2_000.0), 0.0);
+    pool.withdraw(alice, pool.user_lp_amount_f64(alice));
+    pool.swap(bob, bob, 0.1, 0.0, Direction::A2B);
+
+    // The fees of the last withdrawal and of the swap
+    let undistributed = pool.client.get_pool().undistributed_rewards.data;
+    assert!(undistributed.0 > 0);
+    assert!(undistributed.1 > 0);
+
+    pool.deposit(bob, (1_000.0, 1_000.0), 0.0);
+
+    assert_eq!(pool.client.get_pool().undistributed_rewards.data, (0, 0));
+    let (pending_a, pending_b) = pool.client.pending_reward(bob.as_ref());
+    assert_rel_eq(pending_a, undistributed.0, 1);
+    assert_rel_eq(pending_b, undistributed.1, 1);
+}
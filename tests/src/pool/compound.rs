@@ -0,0 +1,163 @@
+use crate::{
+    contracts::pool::Direction,
+    utils::{float_to_uint, TestingEnv, TestingEnvConfig},
+};
+
+/// Alice holds half of the LP and earns the fees of two swaps by bob
+fn accrue_rewards(testing_env: &TestingEnv) {
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref bob,
+        ..
+    } = testing_env;
+
+    pool.deposit(alice, (100_000.0, 100_000.0), 0.0);
+    pool.swap(bob, bob, 10_000.0, 0.0, Direction::A2B);
+    pool.swap(bob, bob, 10_000.0, 0.0, Direction::B2A);
+}
+
+/// Only the system precision dust of the compounded rewards is left pending
+fn assert_dust_pending(testing_env: &TestingEnv) {
+    let (pending_a, pending_b) = testing_env
+        .pool
+        .client
+        .pending_reward(testing_env.alice.as_ref());
+
+    assert!(pending_a < 10_000 && pending_b < 10_000);
+}
+
+fn create_testing_env() -> TestingEnv {
+    TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0))
+}
+
+#[test]
+fn compound_rewards() {
+    let testing_env = create_testing_env();
+    accrue_rewards(&testing_env);
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref yusd_token,
+        ref yaro_token,
+        ..
+    } = testing_env;
+
+    let lp_before = pool.user_deposit(alice).lp_amount;
+    let balances_before = (
+        yusd_token.balance_of(alice.as_ref()),
+        yaro_token.balance_of(alice.as_ref()),
+    );
+
+    // About 100 of each token in fees, half of them to alice
+    let lp_amount = pool.compound_rewards(alice, 99.0);
+
+    assert_eq!(pool.user_deposit(alice).lp_amount - lp_before, lp_amount);
+    assert_dust_pending(&testing_env);
+    assert_eq!(
+        (
+            yusd_token.balance_of(alice.as_ref()),
+            yaro_token.balance_of(alice.as_ref()),
+        ),
+        balances_before
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(Slippage)"]
+fn compound_rewards_slippage() {
+    let testing_env = create_testing_env();
+    accrue_rewards(&testing_env);
+
+    testing_env
+        .pool
+        .compound_rewards(&testing_env.alice, 1_000.0);
+}
+
+#[test]
+#[should_panic = "DexContract(ZeroAmount)"]
+fn compound_rewards_no_rewards() {
+    let testing_env = create_testing_env();
+
+    testing_env
+        .pool
+        .deposit(&testing_env.alice, (1_000.0, 1_000.0), 0.0);
+    testing_env.pool.compound_rewards(&testing_env.alice, 0.0);
+}
+
+#[test]
+fn auto_compound_on_deposit() {
+    let testing_env = create_testing_env();
+    testing_env.pool.set_auto_compound(&testing_env.alice, true);
+    accrue_rewards(&testing_env);
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref yusd_token,
+        ..
+    } = testing_env;
+
+    let yusd_before = yusd_token.balance_of(alice.as_ref());
+    pool.deposit(alice, (1_000.0, 1_000.0), 0.0);
+
+    // Only the deposit is taken and the rewards are not paid out
+    assert_eq!(
+        yusd_before - yusd_token.balance_of(alice.as_ref()),
+        float_to_uint(1_000.0, 7)
+    );
+    assert!(pool.user_lp_amount_f64(alice) > 202_090.0);
+    assert_dust_pending(&testing_env);
+}
+
+#[test]
+fn auto_compound_on_withdraw() {
+    let testing_env = create_testing_env();
+    testing_env.pool.set_auto_compound(&testing_env.alice, true);
+    accrue_rewards(&testing_env);
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ..
+    } = testing_env;
+
+    pool.withdraw(alice, 100_000.0);
+
+    assert!(pool.user_lp_amount_f64(alice) > 100_000.0);
+    assert_dust_pending(&testing_env);
+}
+
+#[test]
+fn auto_compound_on_full_withdraw() {
+    let testing_env = create_testing_env();
+    testing_env.pool.set_auto_compound(&testing_env.alice, true);
+    accrue_rewards(&testing_env);
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref yusd_token,
+        ..
+    } = testing_env;
+
+    let (pending_a, _) = pool.client.pending_reward(alice.as_ref());
+    let balance_before = yusd_token.balance_of(alice.as_ref());
+    pool.withdraw(alice, 200_000.0);
+
+    // Nothing is re-minted to a closed position, the rewards are paid out
+    assert_eq!(pool.user_lp_amount_f64(alice), 0.0);
+    assert_eq!(pool.client.pending_reward(alice.as_ref()), (0, 0));
+    assert!(yusd_token.balance_of(alice.as_ref()) - balance_before > pending_a);
+}
+
+#[test]
+fn auto_compound_disabled() {
+    let testing_env = create_testing_env();
+    testing_env.pool.set_auto_compound(&testing_env.alice, true);
+    testing_env
+        .pool
+        .set_auto_compound(&testing_env.alice, false);
+    accrue_rewards(&testing_env);
+
+    testing_env.pool.withdraw(&testing_env.alice, 200_000.0);
+
+    assert_eq!(testing_env.pool.user_lp_amount_f64(&testing_env.alice), 0.0);
+}
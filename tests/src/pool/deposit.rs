@@ -1,8 +1,11 @@
 use test_case::test_case;
 
 use crate::{
-    contracts::pool::Direction,
-    utils::{Snapshot, TestingEnv, TestingEnvConfig, DOUBLE_ZERO},
+    contracts::pool::{Deposit, Direction},
+    utils::{
+        float_to_uint, float_to_uint_sp, get_latest_event, Snapshot, TestingEnv, TestingEnvConfig,
+        DOUBLE_ZERO,
+    },
 };
 
 #[test]
@@ -50,6 +53,37 @@ fn deposit_invalid_first_deposit() {
         .deposit(&testing_env.alice, (100.0, 25.0), 0.0);
 }
 
+#[test]
+#[should_panic = "DexContract(InvalidFirstDeposit)"]
+fn deposit_first_deposit_below_locked_lp() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_admin_init_deposit(0.0));
+    testing_env
+        .pool
+        .deposit(&testing_env.alice, (0.5, 0.5), 0.0);
+}
+
+#[test]
+fn deposit_first_deposit_locks_lp() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_admin_init_deposit(0.0));
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ..
+    } = testing_env;
+
+    pool.deposit(alice, (100.0, 100.0), 0.0);
+
+    let locked_lp = pool.total_lp() - pool.user_deposit(alice).lp_amount;
+    assert_eq!(locked_lp, 1_000);
+    assert_eq!(pool.client.get_user_deposit(&pool.id).lp_amount, 0);
+    assert_eq!(pool.user_lp_amount_f64(alice), 199.0);
+    assert_eq!(pool.total_lp(), float_to_uint_sp(200.0));
+
+    // Total LP can't be driven below the locked amount
+    pool.withdraw(alice, 199.0);
+    assert_eq!(pool.total_lp(), locked_lp);
+}
+
 #[test_case((100.0, 50.0), DOUBLE_ZERO, 150.0 ; "base")]
 #[test_case((50_000_000.0, 5_000.0), DOUBLE_ZERO, 31_492_001.07 ; "deposit_disbalance")]
 #[test_case((0.001, 0.001), DOUBLE_ZERO, 0.002 ; "smallest_deposit")]
@@ -102,10 +136,11 @@ fn get_reward_after_second_deposit() {
     } = testing_env;
 
     let deposit = (2_000.0, 2_000.0);
+    // The locked LP takes no share of the rewards
     let expected_rewards = (1.001_219_9, 0.998_779_9);
     let expected_lp_amount = 4_000.0;
 
-    pool.deposit(alice, deposit, 4_000.0);
+    pool.deposit(alice, deposit, 3_999.0);
     pool.swap(alice, bob, 100.0, 98.0, Direction::A2B);
     pool.swap(bob, alice, 100.0, 99.0, Direction::B2A);
 
@@ -147,3 +182,32 @@ fn deposit_() {
 
     // pool.deposit(alice, (1.0, 1.0), 0.0);
 }
+
+#[test]
+fn deposit_for() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref bob,
+        ref yusd_token,
+        ..
+    } = testing_env;
+
+    let alice_yusd_before = yusd_token.balance_of(alice.as_ref());
+    let bob_yusd_before = yusd_token.balance_of(bob.as_ref());
+    pool.deposit_for(alice, bob, (1_000.0, 1_000.0), 1_999.0);
+
+    assert_eq!(pool.user_lp_amount_f64(alice), 0.0);
+    assert_eq!(pool.user_lp_amount_f64(bob), 2_000.0);
+    assert_eq!(
+        alice_yusd_before - yusd_token.balance_of(alice.as_ref()),
+        float_to_uint(1_000.0, 7)
+    );
+    assert_eq!(yusd_token.balance_of(bob.as_ref()), bob_yusd_before);
+
+    let deposit = get_latest_event::<Deposit>(&testing_env.env).unwrap();
+    assert_eq!(deposit.user, alice.as_address());
+    assert_eq!(deposit.beneficiary, bob.as_address());
+    assert_eq!(deposit.lp_amount, float_to_uint_sp(2_000.0));
+}
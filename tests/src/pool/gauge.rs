@@ -0,0 +1,175 @@
+use crate::utils::{assert_rel_eq, float_to_uint, TestingEnv, TestingEnvConfig, Token};
+
+/// Reward token streaming 1_000 over 1_000 seconds, alice holds half of the LP
+fn create_reward_token(testing_env: &TestingEnv) -> Token {
+    let reward_token = Token::create(&testing_env.env, testing_env.admin.as_ref());
+    reward_token.airdrop(&testing_env.admin, 10_000.0);
+
+    testing_env
+        .pool
+        .deposit(&testing_env.alice, (100_000.0, 100_000.0), 0.0);
+
+    testing_env.pool.add_reward_token(&reward_token.id);
+    testing_env
+        .pool
+        .notify_reward_amount(&reward_token.id, 1_000.0, 1_000);
+
+    reward_token
+}
+
+#[test]
+fn reward_token_streamed() {
+    let testing_env = TestingEnv::default();
+    let reward_token = create_reward_token(&testing_env);
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ..
+    } = testing_env;
+
+    testing_env.skip_time(500);
+    let pending = pool.pending_reward_token(alice, &reward_token.id);
+    assert_rel_eq(pending, float_to_uint(250.0, 7), float_to_uint(0.01, 7));
+
+    let claimed = pool.claim_all(alice);
+    assert_eq!(claimed.len(), 3);
+    assert_eq!(claimed.get(2).unwrap(), (reward_token.id.clone(), pending));
+    assert_eq!(reward_token.balance_of(alice.as_ref()), pending);
+    assert_eq!(pool.pending_reward_token(alice, &reward_token.id), 0);
+}
+
+#[test]
+fn reward_token_period_finished() {
+    let testing_env = TestingEnv::default();
+    let reward_token = create_reward_token(&testing_env);
+
+    testing_env.skip_time(2_000);
+
+    assert_rel_eq(
+        testing_env
+            .pool
+            .pending_reward_token(&testing_env.alice, &reward_token.id),
+        float_to_uint(500.0, 7),
+        float_to_uint(0.01, 7),
+    );
+}
+
+#[test]
+fn reward_token_kept_after_withdraw() {
+    let testing_env = TestingEnv::default();
+    let reward_token = create_reward_token(&testing_env);
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref bob,
+        ..
+    } = testing_env;
+
+    testing_env.skip_time(500);
+    pool.withdraw(alice, pool.user_lp_amount_f64(alice));
+    pool.deposit(bob, (100_000.0, 100_000.0), 0.0);
+    testing_env.skip_time(500);
+
+    // Bob only gets the second half of the period
+    assert_rel_eq(
+        pool.pending_reward_token(alice, &reward_token.id),
+        float_to_uint(250.0, 7),
+        float_to_uint(0.01, 7),
+    );
+    assert_rel_eq(
+        pool.pending_reward_token(bob, &reward_token.id),
+        float_to_uint(250.0, 7),
+        float_to_uint(0.01, 7),
+    );
+}
+
+#[test]
+fn notify_reward_amount_extends_period() {
+    let testing_env = TestingEnv::default();
+    let reward_token = create_reward_token(&testing_env);
+    let TestingEnv { ref pool, .. } = testing_env;
+
+    testing_env.skip_time(500);
+    pool.notify_reward_amount(&reward_token.id, 500.0, 1_000);
+
+    // 500 left of the first notify and 500 new over 1_000 seconds
+    let schedule = pool.client.get_reward_schedule(&reward_token.id);
+    assert_eq!(schedule.rate, float_to_uint(1.0, 7));
+    assert_eq!(
+        schedule.period_finish,
+        testing_env.env.ledger().timestamp() + 1_000
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn add_reward_token_pool_token() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .pool
+        .add_reward_token(&testing_env.yusd_token.id);
+}
+
+#[test]
+#[should_panic = "DexContract(NotFound)"]
+fn notify_reward_amount_unknown_token() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .pool
+        .notify_reward_amount(&testing_env.native_token.id, 1_000.0, 1_000);
+}
+
+#[test]
+fn reward_token_streamed_without_lp_carried_forward() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_admin_init_deposit(0.0));
+    let TestingEnv {
+        ref pool,
+        ref admin,
+        ref alice,
+        ..
+    } = testing_env;
+    let reward_token = Token::create(&testing_env.env, admin.as_ref());
+    reward_token.airdrop(admin, 1_000.0);
+
+    pool.add_reward_token(&reward_token.id);
+    pool.notify_reward_amount(&reward_token.id, 1_000.0, 1_000);
+
+    testing_env.skip_time(500);
+    pool.deposit(alice, (100_000.0, 100_000.0), 0.0);
+    testing_env.skip_time(500);
+
+    // The first half streamed with no LP goes to the first depositor
+    assert_rel_eq(
+        pool.pending_reward_token(alice, &reward_token.id),
+        float_to_uint(1_000.0, 7),
+        float_to_uint(0.01, 7),
+    );
+}
+
+#[test]
+fn reward_token_large_amount() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref pool,
+        ref admin,
+        ref alice,
+        ..
+    } = testing_env;
+    let reward_token = Token::create(&testing_env.env, admin.as_ref());
+    reward_token.airdrop(admin, 1e18);
+
+    pool.deposit(alice, (100_000.0, 100_000.0), 0.0);
+    pool.add_reward_token(&reward_token.id);
+    pool.notify_reward_amount(&reward_token.id, 1e18, 1_000);
+
+    // The streamed amount shifted by `Pool::P` does not fit into u128
+    testing_env.skip_time(500);
+
+    assert_rel_eq(
+        pool.pending_reward_token(alice, &reward_token.id),
+        float_to_uint(2.5e17, 7),
+        float_to_uint(1e12, 7),
+    );
+}
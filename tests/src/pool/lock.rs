@@ -0,0 +1,127 @@
+use crate::{
+    contracts::pool::Direction,
+    utils::{assert_rel_eq, float_to_uint_sp, TestingEnv, TestingEnvConfig},
+};
+
+const MONTH: u64 = 30 * 24 * 60 * 60;
+
+/// Alice and bob deposit the same amounts, alice locks 1_000 LP for 6 months
+fn create_lock(testing_env: &TestingEnv) {
+    testing_env
+        .pool
+        .deposit(&testing_env.alice, (1_000.0, 1_000.0), 0.0);
+    testing_env
+        .pool
+        .deposit(&testing_env.bob, (1_000.0, 1_000.0), 0.0);
+
+    testing_env.pool.lock(&testing_env.alice, 1_000.0, 6);
+}
+
+#[test]
+fn lock_info() {
+    let testing_env = TestingEnv::default();
+    create_lock(&testing_env);
+
+    let user_deposit = testing_env.pool.user_deposit(&testing_env.alice);
+    assert_eq!(user_deposit.lp_amount, float_to_uint_sp(2_000.0));
+    assert_eq!(user_deposit.locked_amount, float_to_uint_sp(1_000.0));
+    assert_eq!(user_deposit.lock_weight_bp, 15_000);
+    assert_eq!(
+        user_deposit.unlock_time,
+        testing_env.env.ledger().timestamp() + 6 * MONTH
+    );
+    assert_eq!(
+        testing_env.pool.client.get_pool().total_reward_shares,
+        float_to_uint_sp(204_499.0)
+    );
+}
+
+#[test]
+fn locked_lp_boosted_rewards() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0));
+    create_lock(&testing_env);
+
+    testing_env.pool.swap(
+        &testing_env.bob,
+        &testing_env.bob,
+        10_000.0,
+        0.0,
+        Direction::A2B,
+    );
+
+    // 1_000 unlocked and 1_000 locked with 1.5x weight
+    let (alice_a, alice_b) = testing_env
+        .pool
+        .client
+        .pending_reward(testing_env.alice.as_ref());
+    let (bob_a, bob_b) = testing_env
+        .pool
+        .client
+        .pending_reward(testing_env.bob.as_ref());
+    assert_eq!(alice_a, 0);
+    assert_eq!(bob_a, 0);
+    assert_rel_eq(alice_b * 4, bob_b * 5, bob_b / 1_000);
+}
+
+#[test]
+#[should_panic = "DexContract(LpLocked)"]
+fn withdraw_locked_lp() {
+    let testing_env = TestingEnv::default();
+    create_lock(&testing_env);
+
+    testing_env.pool.withdraw(&testing_env.alice, 1_001.0);
+}
+
+#[test]
+fn withdraw_after_unlock_time() {
+    let testing_env = TestingEnv::default();
+    create_lock(&testing_env);
+
+    testing_env.pool.withdraw(&testing_env.alice, 1_000.0);
+    testing_env.skip_time(6 * MONTH);
+    testing_env.pool.withdraw(&testing_env.alice, 1_000.0);
+
+    let user_deposit = testing_env.pool.user_deposit(&testing_env.alice);
+    assert_eq!(user_deposit.lp_amount, 0);
+    assert_eq!(user_deposit.locked_amount, 0);
+    assert_eq!(
+        testing_env.pool.client.get_pool().total_reward_shares,
+        float_to_uint_sp(201_999.0)
+    );
+}
+
+#[test]
+fn unlock() {
+    let testing_env = TestingEnv::default();
+    create_lock(&testing_env);
+
+    testing_env.skip_time(6 * MONTH);
+    testing_env.pool.unlock(&testing_env.alice);
+
+    let user_deposit = testing_env.pool.user_deposit(&testing_env.alice);
+    assert_eq!(user_deposit.lp_amount, float_to_uint_sp(2_000.0));
+    assert_eq!(user_deposit.locked_amount, 0);
+    assert_eq!(
+        testing_env.pool.client.get_pool().total_reward_shares,
+        float_to_uint_sp(203_999.0)
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(LpLocked)"]
+fn unlock_before_unlock_time() {
+    let testing_env = TestingEnv::default();
+    create_lock(&testing_env);
+
+    testing_env.skip_time(MONTH);
+    testing_env.pool.unlock(&testing_env.alice);
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn lock_too_long() {
+    let testing_env = TestingEnv::default();
+    create_lock(&testing_env);
+
+    testing_env.pool.lock(&testing_env.bob, 1_000.0, 13);
+}
@@ -0,0 +1,288 @@
+use crate::{
+    contracts::pool::Direction,
+    utils::{
+        assert_rel_eq, float_to_uint, float_to_uint_sp, Pool, TestingEnv, TestingEnvConfig, Token,
+    },
+};
+
+struct Metapool {
+    pool: Pool,
+    /// Token A of the metapool, token B is the LP of `testing_env.pool`
+    token: Token,
+}
+
+/// Metapool of a new token against the yusd/yaro pool LP,
+/// alice deposits (50_000 token, 50_000 LP) out of her base pool deposit
+fn create_metapool(testing_env: &TestingEnv) -> Metapool {
+    let (token, _) = testing_env.generate_listed_token_pair();
+
+    let pool = testing_env.factory.create_metapool(
+        testing_env.admin.as_ref(),
+        20,
+        &token.id,
+        (&testing_env.yusd_token.id, &testing_env.yaro_token.id),
+        0,
+        0,
+    );
+    let pool = Pool::new(&testing_env.env, pool);
+
+    token.airdrop(&testing_env.alice, 1_000_000.0);
+    token.airdrop(&testing_env.bob, 1_000_000.0);
+
+    testing_env
+        .pool
+        .deposit(&testing_env.alice, (100_000.0, 100_000.0), 0.0);
+    pool.client.deposit(
+        testing_env.alice.as_ref(),
+        &(float_to_uint(50_000.0, 7), float_to_uint_sp(50_000.0)),
+        &0,
+    );
+
+    Metapool { pool, token }
+}
+
+#[test]
+fn metapool_deposit() {
+    let testing_env = TestingEnv::default();
+    let Metapool { pool, token } = create_metapool(&testing_env);
+
+    let pool_info = pool.client.get_pool();
+    assert_eq!(
+        pool_info.tokens.data,
+        (token.id, testing_env.pool.id.clone())
+    );
+    assert_eq!(pool_info.token_balances.data, (50_000_000, 50_000_000));
+    assert_eq!(
+        testing_env.pool.client.get_user_deposit(&pool.id).lp_amount,
+        float_to_uint_sp(50_000.0)
+    );
+    assert_eq!(
+        testing_env.pool.user_lp_amount_f64(&testing_env.alice),
+        150_000.0
+    );
+}
+
+#[test]
+fn metapool_swap() {
+    let testing_env = TestingEnv::default();
+    let Metapool { pool, .. } = create_metapool(&testing_env);
+
+    // Received LP is in the base pool system precision
+    let lp_before = testing_env.pool.user_deposit(&testing_env.alice).lp_amount;
+    let received = pool.client.swap(
+        testing_env.alice.as_ref(),
+        testing_env.alice.as_ref(),
+        &float_to_uint(1_000.0, 7),
+        &float_to_uint_sp(990.0),
+        &Direction::A2B,
+    );
+    let lp_received = testing_env.pool.user_deposit(&testing_env.alice).lp_amount - lp_before;
+
+    assert_eq!(lp_received, received);
+    assert_rel_eq(
+        lp_received,
+        float_to_uint_sp(1_000.0),
+        float_to_uint_sp(1.0),
+    );
+}
+
+#[test]
+fn swap_underlying_to_token() {
+    let testing_env = TestingEnv::default();
+    let Metapool { pool, token } = create_metapool(&testing_env);
+
+    let bob_token_before = token.balance_of(testing_env.bob.as_ref());
+    let received = pool.swap_underlying(
+        &testing_env.bob,
+        &testing_env.bob,
+        1_000.0,
+        990.0,
+        &testing_env.yusd_token.id,
+        &token.id,
+    );
+
+    assert_eq!(
+        token.balance_of(testing_env.bob.as_ref()) - bob_token_before,
+        received
+    );
+    assert_rel_eq(received, float_to_uint(1_000.0, 7), float_to_uint(1.0, 7));
+    // The base pool LP minted to bob went to the metapool
+    assert_eq!(testing_env.pool.user_deposit(&testing_env.bob).lp_amount, 0);
+}
+
+#[test]
+fn swap_underlying_to_token_auto_compound() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0));
+    let Metapool { pool, token } = create_metapool(&testing_env);
+    let base_pool = &testing_env.pool;
+
+    base_pool.deposit(&testing_env.bob, (1_000.0, 1_000.0), 0.0);
+    base_pool.set_auto_compound(&testing_env.bob, true);
+    base_pool.swap(
+        &testing_env.alice,
+        &testing_env.alice,
+        10_000.0,
+        0.0,
+        Direction::A2B,
+    );
+
+    let base_lp_before = base_pool.user_deposit(&testing_env.bob).lp_amount;
+    let received = pool.swap_underlying(
+        &testing_env.bob,
+        &testing_env.bob,
+        1_000.0,
+        980.0,
+        &testing_env.yusd_token.id,
+        &token.id,
+    );
+
+    // Only the LP minted for the yusd is swapped, the compounded rewards stay with bob
+    assert!(base_pool.user_deposit(&testing_env.bob).lp_amount > base_lp_before);
+    assert_rel_eq(received, float_to_uint(1_000.0, 7), float_to_uint(20.0, 7));
+}
+
+#[test]
+fn swap_underlying_from_token() {
+    let testing_env = TestingEnv::default();
+    let Metapool { pool, token } = create_metapool(&testing_env);
+
+    let alice_yaro_before = testing_env
+        .yaro_token
+        .balance_of(testing_env.alice.as_ref());
+    let received = pool.swap_underlying(
+        &testing_env.bob,
+        &testing_env.alice,
+        1_000.0,
+        990.0,
+        &token.id,
+        &testing_env.yaro_token.id,
+    );
+
+    assert_eq!(
+        testing_env
+            .yaro_token
+            .balance_of(testing_env.alice.as_ref())
+            - alice_yaro_before,
+        received
+    );
+    assert_rel_eq(received, float_to_uint(1_000.0, 7), float_to_uint(1.0, 7));
+    assert_eq!(testing_env.pool.user_deposit(&testing_env.bob).lp_amount, 0);
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn swap_underlying_base_tokens() {
+    let testing_env = TestingEnv::default();
+    let Metapool { pool, .. } = create_metapool(&testing_env);
+
+    pool.swap_underlying(
+        &testing_env.bob,
+        &testing_env.bob,
+        1_000.0,
+        0.0,
+        &testing_env.yusd_token.id,
+        &testing_env.yaro_token.id,
+    );
+}
+
+#[test]
+fn claim_base_rewards() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0));
+    let Metapool { pool, .. } = create_metapool(&testing_env);
+
+    testing_env.pool.swap(
+        &testing_env.bob,
+        &testing_env.bob,
+        10_000.0,
+        0.0,
+        Direction::A2B,
+    );
+
+    let admin_balance = || {
+        testing_env
+            .yusd_token
+            .balance_of(testing_env.admin.as_ref())
+            + testing_env
+                .yaro_token
+                .balance_of(testing_env.admin.as_ref())
+    };
+    let admin_before = admin_balance();
+    let base_lp_before = testing_env.pool.client.get_user_deposit(&pool.id).lp_amount;
+    let (claimed_a, claimed_b) = pool.claim_base_rewards();
+
+    assert!(claimed_a + claimed_b > 0);
+    assert_eq!(admin_balance(), admin_before);
+
+    // The base LP of the claimed rewards is pending for the metapool LPs
+    let base_lp = testing_env.pool.client.get_user_deposit(&pool.id).lp_amount - base_lp_before;
+    let (_, pending_lp) = pool.client.pending_reward(testing_env.alice.as_ref());
+    assert!(base_lp > 0);
+    assert_rel_eq(pending_lp, base_lp, 1);
+}
+
+#[test]
+fn transfer_lp() {
+    let testing_env = TestingEnv::default();
+    testing_env
+        .pool
+        .deposit(&testing_env.alice, (1_000.0, 1_000.0), 0.0);
+
+    testing_env
+        .pool
+        .transfer_lp(&testing_env.alice, &testing_env.bob, 500.0);
+
+    assert_eq!(
+        testing_env.pool.user_lp_amount_f64(&testing_env.alice),
+        1_500.0
+    );
+    assert_eq!(testing_env.pool.user_lp_amount_f64(&testing_env.bob), 500.0);
+    assert_eq!(testing_env.pool.total_lp(), float_to_uint_sp(202_000.0));
+}
+
+#[test]
+#[should_panic = "DexContract(NotEnoughAmount)"]
+fn transfer_lp_not_enough_amount() {
+    let testing_env = TestingEnv::default();
+    testing_env
+        .pool
+        .deposit(&testing_env.alice, (1_000.0, 1_000.0), 0.0);
+
+    testing_env
+        .pool
+        .transfer_lp(&testing_env.alice, &testing_env.bob, 2_001.0);
+}
+
+#[test]
+fn replace_metapool() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref factory,
+        ref admin,
+        ref yusd_token,
+        ref yaro_token,
+        ..
+    } = testing_env;
+    let Metapool { pool, token } = create_metapool(&testing_env);
+    let base_pool = testing_env.pool.id.clone();
+
+    let new_pool = factory.client.create_metapool(
+        admin.as_ref(),
+        admin.as_ref(),
+        &50,
+        &token.id,
+        &yusd_token.id,
+        &yaro_token.id,
+        &0,
+        &0,
+        &0,
+        &1,
+    );
+    factory.remove_pair(&token.id, &base_pool, 1, false);
+
+    // The base pool LP is not a listed token
+    let replaced = factory.replace_pair(&token.id, &base_pool, 0, &new_pool, true);
+
+    assert_eq!(replaced, pool.id);
+    assert_eq!(factory.pool(&token.id, &base_pool), new_pool);
+    assert!(pool.client.get_pool().paused);
+}
@@ -1,7 +1,15 @@
 pub mod admin;
 pub mod claims;
+pub mod compound;
 pub mod deposit;
+pub mod gauge;
+pub mod lock;
+pub mod metapool;
+pub mod multicall;
+pub mod rates;
+pub mod signed_swap;
 pub mod swap;
+pub mod view;
 pub mod withdraw;
 
 pub struct DepositArgs {
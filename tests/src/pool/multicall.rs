@@ -0,0 +1,78 @@
+use soroban_sdk::vec;
+
+use crate::{
+    contracts::pool::{Direction, PoolOp, Swapped},
+    utils::{float_to_uint, float_to_uint_sp, get_latest_event, TestingEnv, TestingEnvConfig},
+};
+
+#[test]
+fn multicall_claim_swap_deposit() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0));
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref alice,
+        ref bob,
+        ..
+    } = testing_env;
+
+    pool.deposit(alice, (1_000.0, 1_000.0), 0.0);
+    pool.swap(bob, bob, 1_000.0, 0.0, Direction::A2B);
+    let lp_before = pool.user_deposit(alice).lp_amount;
+
+    let results = pool.multicall(
+        alice,
+        vec![
+            env,
+            PoolOp::ClaimRewards,
+            PoolOp::Swap(float_to_uint(100.0, 7), 0, Direction::B2A),
+            PoolOp::Deposit((float_to_uint(100.0, 7), float_to_uint(100.0, 7)), 0),
+        ],
+    );
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results.get(0).unwrap(), 0);
+    assert!(results.get(1).unwrap() > float_to_uint(98.0, 7));
+    assert!(pool.user_deposit(alice).lp_amount - lp_before > float_to_uint_sp(199.0));
+    assert_eq!(pool.client.pending_reward(alice.as_ref()), (0, 0));
+
+    let swapped = get_latest_event::<Swapped>(env).unwrap();
+    assert_eq!(swapped.to_amount, results.get(1).unwrap());
+}
+
+#[test]
+fn multicall_atomic() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref alice,
+        ..
+    } = testing_env;
+
+    let result = pool.multicall_checked(
+        alice,
+        vec![
+            env,
+            PoolOp::Deposit((float_to_uint(100.0, 7), float_to_uint(100.0, 7)), 0),
+            PoolOp::Swap(
+                float_to_uint(100.0, 7),
+                float_to_uint(101.0, 7),
+                Direction::A2B,
+            ),
+        ],
+    );
+
+    assert!(result.is_err());
+    assert_eq!(pool.user_deposit(alice).lp_amount, 0);
+}
+
+#[test]
+#[should_panic = "DexContract(InvalidArg)"]
+fn multicall_no_ops() {
+    let testing_env = TestingEnv::default();
+
+    testing_env
+        .pool
+        .multicall(&testing_env.alice, vec![&testing_env.env]);
+}
@@ -0,0 +1,162 @@
+use crate::{
+    contracts::pool::Direction,
+    utils::{assert_rel_eq, float_to_uint, Pool, RateProvider, TestingEnv, Token},
+};
+
+struct RatedPool {
+    pool: Pool,
+    /// Priced by `rate_provider`
+    token_a: Token,
+    token_b: Token,
+    rate_provider: RateProvider,
+}
+
+/// Pool with the rated token as token A and a deposit of (50_000 A, 100_000 B) from alice
+fn create_rated_pool(testing_env: &TestingEnv, rate: f64) -> RatedPool {
+    let (token_a, token_b) = testing_env.generate_listed_token_pair();
+    let (token_a, token_b) = if token_a.id < token_b.id {
+        (token_a, token_b)
+    } else {
+        (token_b, token_a)
+    };
+
+    let rate_provider = RateProvider::create(&testing_env.env, rate);
+    testing_env
+        .factory
+        .set_token_rate_provider(&token_a.id, Some(rate_provider.id.clone()));
+
+    let pool = testing_env.factory.create_pair(
+        testing_env.admin.as_ref(),
+        20,
+        &token_a.id,
+        &token_b.id,
+        0,
+        0,
+    );
+    let pool = Pool::new(&testing_env.env, pool);
+
+    for token in [&token_a, &token_b] {
+        token.airdrop(&testing_env.alice, 1_000_000.0);
+        token.airdrop(&testing_env.bob, 1_000_000.0);
+    }
+
+    pool.deposit(&testing_env.alice, (50_000.0, 100_000.0), 0.0);
+
+    RatedPool {
+        pool,
+        token_a,
+        token_b,
+        rate_provider,
+    }
+}
+
+#[test]
+fn rated_pool_swap() {
+    let testing_env = TestingEnv::default();
+    let RatedPool {
+        pool,
+        token_a,
+        token_b,
+        ..
+    } = create_rated_pool(&testing_env, 2.0);
+
+    let pool_info = pool.client.get_pool();
+    assert_eq!(pool_info.rates.data.0, float_to_uint(2.0, 18));
+    assert_eq!(pool_info.token_balances.data, (100_000_000, 100_000_000));
+
+    let bob_b_before = token_b.balance_of(testing_env.bob.as_ref());
+    pool.swap(
+        &testing_env.bob,
+        &testing_env.bob,
+        1_000.0,
+        1_990.0,
+        Direction::A2B,
+    );
+    let received = token_b.balance_of(testing_env.bob.as_ref()) - bob_b_before;
+
+    assert_rel_eq(received, float_to_uint(2_000.0, 7), float_to_uint(1.0, 7));
+    assert_eq!(
+        token_a.balance_of(&pool.id),
+        float_to_uint(51_000.0, 7),
+        "Swap input is not rated"
+    );
+}
+
+#[test]
+fn rate_increase_credited_to_rewards() {
+    let testing_env = TestingEnv::default();
+    let RatedPool {
+        pool,
+        rate_provider,
+        ..
+    } = create_rated_pool(&testing_env, 2.0);
+
+    // Cached rate is used until it is older than the max age
+    rate_provider.set_rate(2.5);
+    pool.swap(&testing_env.bob, &testing_env.bob, 1.0, 0.0, Direction::B2A);
+    assert_eq!(
+        pool.client.pending_reward(testing_env.alice.as_ref()),
+        (0, 0)
+    );
+
+    testing_env.skip_time(300);
+    pool.swap(&testing_env.bob, &testing_env.bob, 1.0, 0.0, Direction::B2A);
+
+    // 50_000 A of the balance are backed by 40_000 A at the new rate
+    let (a_rewards, b_rewards) = pool.client.pending_reward(testing_env.alice.as_ref());
+    assert_rel_eq(a_rewards, float_to_uint(10_000.0, 7), float_to_uint(1.0, 7));
+    assert_eq!(b_rewards, 0);
+    assert_eq!(pool.client.get_pool().rates.data.0, float_to_uint(2.5, 18));
+}
+
+#[test]
+fn rate_decrease_charged_to_lp() {
+    let testing_env = TestingEnv::default();
+    let RatedPool {
+        pool,
+        rate_provider,
+        ..
+    } = create_rated_pool(&testing_env, 2.0);
+    let virtual_price = pool.client.get_virtual_price();
+
+    rate_provider.set_rate(1.8);
+    testing_env.skip_time(300);
+
+    pool.swap(&testing_env.bob, &testing_env.bob, 1.0, 0.0, Direction::B2A);
+
+    // 50_000 A are now worth 90_000 of the balance instead of 100_000
+    let pool_info = pool.client.get_pool();
+    assert_eq!(pool_info.rates.data.0, float_to_uint(1.8, 18));
+    assert_rel_eq(pool_info.token_balances.data.0, 89_999_500, 1_000);
+    assert!(pool.client.get_virtual_price() < virtual_price);
+    assert_eq!(
+        pool.client.pending_reward(testing_env.alice.as_ref()),
+        (0, 0)
+    );
+}
+
+#[test]
+fn rate_decrease_withdraw() {
+    let testing_env = TestingEnv::default();
+    let RatedPool {
+        pool,
+        token_a,
+        rate_provider,
+        ..
+    } = create_rated_pool(&testing_env, 2.0);
+
+    rate_provider.set_rate(1.8);
+    testing_env.skip_time(300);
+
+    let alice_a_before = token_a.balance_of(testing_env.alice.as_ref());
+    let lp_amount = pool.user_lp_amount_f64(&testing_env.alice);
+    pool.withdraw(&testing_env.alice, lp_amount);
+
+    // Withdrawn at the new rate, the pool still holds the tokens backing the locked LP
+    assert_eq!(pool.client.get_pool().rates.data.0, float_to_uint(1.8, 18));
+    assert_rel_eq(
+        token_a.balance_of(testing_env.alice.as_ref()) - alice_a_before,
+        float_to_uint(50_000.0, 7),
+        float_to_uint(1.0, 7),
+    );
+}
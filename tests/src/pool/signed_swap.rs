@@ -0,0 +1,164 @@
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{xdr::ToXdr, BytesN};
+
+use crate::{
+    contracts::pool::{Direction, SwapIntent},
+    utils::{assert_rel_eq, float_to_uint, TestingEnv},
+};
+
+/// Alice signs a swap of 1_000 yusd for at least 990 yaro and approves it to the pool
+fn create_intent(testing_env: &TestingEnv) -> (SigningKey, SwapIntent) {
+    let TestingEnv {
+        ref env,
+        ref pool,
+        ref alice,
+        ref yusd_token,
+        ..
+    } = testing_env;
+
+    let signing_key = SigningKey::from_bytes(&[7; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    pool.set_intent_signer(alice, &public_key);
+
+    yusd_token.client.approve(
+        alice.as_ref(),
+        &pool.id,
+        &(float_to_uint(1_000.0, 7) as i128),
+        &(env.ledger().sequence() + 1_000),
+    );
+
+    let intent = SwapIntent {
+        pool: pool.id.clone(),
+        owner: alice.as_address(),
+        amount_in: float_to_uint(1_000.0, 7),
+        min_amount_out: float_to_uint(990.0, 7),
+        direction: Direction::A2B,
+        nonce: 1,
+        expiry: env.ledger().timestamp() + 100,
+    };
+
+    (signing_key, intent)
+}
+
+fn sign(testing_env: &TestingEnv, signing_key: &SigningKey, intent: &SwapIntent) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = intent.clone().to_xdr(&testing_env.env).iter().collect();
+
+    BytesN::from_array(&testing_env.env, &signing_key.sign(&message).to_bytes())
+}
+
+#[test]
+fn execute_signed_swap() {
+    let testing_env = TestingEnv::default();
+    let (signing_key, intent) = create_intent(&testing_env);
+    let signature = sign(&testing_env, &signing_key, &intent);
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref yusd_token,
+        ref yaro_token,
+        ..
+    } = testing_env;
+
+    let yusd_before = yusd_token.balance_of(alice.as_ref());
+    let yaro_before = yaro_token.balance_of(alice.as_ref());
+    let received = pool.execute_signed_swap(&intent, &signature, 1_000.0);
+
+    assert_eq!(
+        yusd_before - yusd_token.balance_of(alice.as_ref()),
+        float_to_uint(1_000.0, 7)
+    );
+    assert_eq!(
+        yaro_token.balance_of(alice.as_ref()) - yaro_before,
+        received
+    );
+    assert_rel_eq(received, float_to_uint(1_000.0, 7), float_to_uint(1.0, 7));
+}
+
+#[test]
+fn execute_signed_swap_partially() {
+    let testing_env = TestingEnv::default();
+    let (signing_key, intent) = create_intent(&testing_env);
+    let signature = sign(&testing_env, &signing_key, &intent);
+
+    testing_env
+        .pool
+        .execute_signed_swap(&intent, &signature, 400.0);
+    testing_env
+        .pool
+        .execute_signed_swap(&intent, &signature, 600.0);
+
+    assert_eq!(
+        testing_env
+            .yusd_token
+            .client
+            .allowance(testing_env.alice.as_ref(), &testing_env.pool.id),
+        0
+    );
+}
+
+#[test]
+#[should_panic = "DexContract(NotEnoughAmount)"]
+fn execute_signed_swap_overfilled() {
+    let testing_env = TestingEnv::default();
+    let (signing_key, intent) = create_intent(&testing_env);
+    let signature = sign(&testing_env, &signing_key, &intent);
+
+    testing_env
+        .pool
+        .execute_signed_swap(&intent, &signature, 600.0);
+    testing_env
+        .pool
+        .execute_signed_swap(&intent, &signature, 600.0);
+}
+
+#[test]
+#[should_panic = "Context(InvalidAction)"]
+fn execute_signed_swap_wrong_signature() {
+    let testing_env = TestingEnv::default();
+    let (signing_key, mut intent) = create_intent(&testing_env);
+    let signature = sign(&testing_env, &signing_key, &intent);
+
+    intent.min_amount_out = 0;
+    testing_env
+        .pool
+        .execute_signed_swap(&intent, &signature, 1_000.0);
+}
+
+#[test]
+#[should_panic = "DexContract(InsufficientReceivedAmount)"]
+fn execute_signed_swap_price_limit() {
+    let testing_env = TestingEnv::default();
+    let (signing_key, mut intent) = create_intent(&testing_env);
+    intent.min_amount_out = float_to_uint(1_000.0, 7);
+    let signature = sign(&testing_env, &signing_key, &intent);
+
+    testing_env
+        .pool
+        .execute_signed_swap(&intent, &signature, 500.0);
+}
+
+#[test]
+#[should_panic = "DexContract(IntentExpired)"]
+fn execute_signed_swap_expired() {
+    let testing_env = TestingEnv::default();
+    let (signing_key, intent) = create_intent(&testing_env);
+    let signature = sign(&testing_env, &signing_key, &intent);
+
+    testing_env.skip_time(101);
+    testing_env
+        .pool
+        .execute_signed_swap(&intent, &signature, 1_000.0);
+}
+
+#[test]
+#[should_panic = "DexContract(NotEnoughAmount)"]
+fn execute_signed_swap_cancelled() {
+    let testing_env = TestingEnv::default();
+    let (signing_key, intent) = create_intent(&testing_env);
+    let signature = sign(&testing_env, &signing_key, &intent);
+
+    testing_env.pool.cancel_intent(&testing_env.alice, 1);
+    testing_env
+        .pool
+        .execute_signed_swap(&intent, &signature, 1.0);
+}
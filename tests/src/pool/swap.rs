@@ -20,6 +20,29 @@ fn swap_insufficient_received_amount() {
     );
 }
 
+#[test]
+fn swap_dust_credited_to_rewards() {
+    let testing_env = TestingEnv::default();
+
+    // 0.000_099_9 Yusd is below the system precision
+    testing_env.pool.swap(
+        &testing_env.alice,
+        &testing_env.alice,
+        1.000_099_9,
+        0.0,
+        Direction::A2B,
+    );
+
+    let (yusd_reward, yaro_reward) = testing_env
+        .pool
+        .client
+        .pending_reward(testing_env.admin.as_ref());
+
+    // Admin holds all LP except the locked amount
+    assert_eq!(yusd_reward, 998);
+    assert_eq!(yaro_reward, 0);
+}
+
 #[test_case(1_000.0, 995.5, Direction::A2B, 998.94006, 0.99994 ; "base")]
 #[test_case(1000.0, 995.5, Direction::B2A, 998.94006, 0.99994 ; "base b2a")]
 #[test_case(0.001, 0.0, Direction::A2B, 0.000_999, 0.0 ; "smallest_swap")]
@@ -0,0 +1,118 @@
+use crate::{
+    contracts::pool::{Direction, Token},
+    utils::{assert_rel_eq, float_to_uint, float_to_uint_sp, TestingEnv, TestingEnvConfig},
+};
+
+const RATE_ONE: u128 = 1_000_000_000_000_000_000;
+
+#[test]
+fn virtual_price_empty_pool() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_admin_init_deposit(0.0));
+
+    assert_eq!(testing_env.pool.client.get_virtual_price(), RATE_ONE);
+    assert_eq!(testing_env.pool.client.get_d(), 0);
+}
+
+#[test]
+fn get_d_from_balances() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0));
+
+    testing_env.pool.swap(
+        &testing_env.alice,
+        &testing_env.alice,
+        50_000.0,
+        0.0,
+        Direction::A2B,
+    );
+
+    let pool_info = testing_env.pool.client.get_pool();
+    let d = testing_env.pool.client.get_d();
+
+    // The invariant of the balances, not the recorded LP supply
+    assert_ne!(
+        pool_info.token_balances.data.0,
+        pool_info.token_balances.data.1
+    );
+    assert_rel_eq(d, float_to_uint_sp(200_000.0), 2);
+    assert_rel_eq(
+        testing_env.pool.client.get_virtual_price(),
+        d * RATE_ONE / pool_info.total_lp_amount,
+        0,
+    );
+    assert_rel_eq(
+        testing_env.pool.client.get_virtual_price(),
+        RATE_ONE,
+        RATE_ONE / 100_000,
+    );
+}
+
+#[test]
+fn quote_curve_swap() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0));
+    let TestingEnv {
+        ref pool, ref bob, ..
+    } = testing_env;
+    let input = float_to_uint(10_000.0, 7);
+
+    let quote = pool.quote_curve_swap(10_000.0, Direction::A2B);
+
+    assert_eq!(
+        (quote.output, quote.fee),
+        pool.client.get_receive_amount(&input, &Token::A)
+    );
+    // Balanced pool
+    assert_eq!(quote.price_before, RATE_ONE);
+    assert!(quote.price_after < quote.price_before);
+    assert_eq!(quote.effective_rate, quote.output * RATE_ONE / input);
+    assert!(quote.price_impact_bp > 0);
+
+    let received = pool
+        .client
+        .swap(bob.as_ref(), bob.as_ref(), &input, &0, &Direction::A2B);
+    assert_eq!(received, quote.output);
+    assert_eq!(
+        pool.client.get_pool().token_balances.data,
+        quote.new_balances
+    );
+
+    // The marginal price after is the one of the next swap
+    let next_quote = pool.quote_curve_swap(10_000.0, Direction::A2B);
+    assert_eq!(next_quote.price_before, quote.price_after);
+    assert!(next_quote.price_impact_bp >= quote.price_impact_bp);
+}
+
+#[test]
+fn quote_curve_swap_exact_out() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_pool_fee_share(1.0));
+    let TestingEnv {
+        ref pool, ref bob, ..
+    } = testing_env;
+    let output = float_to_uint(10_000.0, 7);
+
+    let quote = pool.quote_curve_swap_exact_out(10_000.0, Direction::B2A);
+
+    assert_eq!(
+        (quote.input, quote.fee),
+        pool.client.get_send_amount(&output, &Token::A)
+    );
+    assert_eq!(quote.output, output);
+    assert_eq!(quote.price_before, RATE_ONE);
+    assert!(quote.price_after < quote.price_before);
+
+    let received = pool.client.swap(
+        bob.as_ref(),
+        bob.as_ref(),
+        &quote.input,
+        &0,
+        &Direction::B2A,
+    );
+    assert_rel_eq(received, output, float_to_uint(0.001, 7));
+}
+
+#[test]
+#[should_panic = "DexContract(ZeroAmount)"]
+fn quote_curve_swap_empty_pool() {
+    let testing_env = TestingEnv::create(TestingEnvConfig::default().with_admin_init_deposit(0.0));
+
+    testing_env.pool.quote_curve_swap(1_000.0, Direction::A2B);
+}
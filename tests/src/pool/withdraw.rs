@@ -1,8 +1,11 @@
 use test_case::test_case;
 
 use crate::{
-    contracts::pool::Direction,
-    utils::{assert_rel_eq, float_to_uint, Snapshot, TestingEnv, TestingEnvConfig, DOUBLE_ZERO},
+    contracts::pool::{Direction, Withdraw},
+    utils::{
+        assert_rel_eq, float_to_uint, get_latest_event, Snapshot, TestingEnv, TestingEnvConfig,
+        DOUBLE_ZERO,
+    },
 };
 
 use super::{DepositArgs, DoWithdrawArgs};
@@ -156,10 +159,10 @@ fn withdraw_alice_profit_and_bob_loss() {
     let deposit = (100_000.0, 100_000.0);
     let swap_amount = 100_000.;
     let expected_user_withdraw_lp_diff = 200_000.0;
-    let expected_rewards = (0.0, 49.217_997_4);
+    let expected_rewards = (0.0, 49.218_120_5);
     // Alice should withdraw more than she deposited
     let expected_withdraw_amounts = (149_850.0, 50731.22);
-    let expected_alice_profit = 630.437_997_4;
+    let expected_alice_profit = 630.438_120_5;
     let expected_bob_losses = 1_662.440_995_0;
     let expected_fee = (150.0, 50.782_003);
 
@@ -207,10 +210,10 @@ fn withdraw_alice_loss_and_bob_profit() {
     let deposit = (200_000.0, 0.0);
     let swap_amount = 100_000.;
     let expected_user_withdraw_lp_diff = 198_393.264;
-    let expected_rewards = (50.598_436_60, 0.0);
+    let expected_rewards = (50.598_563_6, 0.0);
     // Alice should withdraw less than she deposited (198_393.304)
     let expected_withdraw_amounts = (98_697.812, 99_497.098);
-    let expected_alice_loss = 1_754.491_563_4;
+    let expected_alice_loss = 1_754.491_436_4;
     let expected_bob_profit = 1_505.050_343;
     let expected_fee = (98.796_609, 99.596_695);
 
@@ -240,3 +243,57 @@ fn withdraw_alice_loss_and_bob_profit() {
     assert_rel_eq(float_to_uint(expected_bob_profit, 7), bob_profit, 1);
     assert_rel_eq(float_to_uint(expected_alice_loss, 7), dbg!(alice_loss), 1);
 }
+
+#[test]
+fn withdraw_to() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref bob,
+        ref yusd_token,
+        ref yaro_token,
+        ..
+    } = testing_env;
+
+    pool.deposit(alice, (1_000.0, 1_000.0), 0.0);
+
+    let alice_yusd_before = yusd_token.balance_of(alice.as_ref());
+    let bob_before = (
+        yusd_token.balance_of(bob.as_ref()),
+        yaro_token.balance_of(bob.as_ref()),
+    );
+    pool.withdraw_to(alice, bob, 2_000.0, (999.0, 999.0));
+
+    assert_eq!(pool.user_lp_amount_f64(alice), 0.0);
+    assert_eq!(yusd_token.balance_of(alice.as_ref()), alice_yusd_before);
+    assert_rel_eq(
+        yusd_token.balance_of(bob.as_ref()) - bob_before.0,
+        float_to_uint(1_000.0, 7),
+        float_to_uint(0.001, 7),
+    );
+    assert_rel_eq(
+        yaro_token.balance_of(bob.as_ref()) - bob_before.1,
+        float_to_uint(1_000.0, 7),
+        float_to_uint(0.001, 7),
+    );
+
+    let withdraw = get_latest_event::<Withdraw>(&testing_env.env).unwrap();
+    assert_eq!(withdraw.user, alice.as_address());
+    assert_eq!(withdraw.recipient, bob.as_address());
+}
+
+#[test]
+#[should_panic = "DexContract(Slippage)"]
+fn withdraw_to_slippage() {
+    let testing_env = TestingEnv::default();
+    let TestingEnv {
+        ref pool,
+        ref alice,
+        ref bob,
+        ..
+    } = testing_env;
+
+    pool.deposit(alice, (1_000.0, 1_000.0), 0.0);
+    pool.withdraw_to(alice, bob, 2_000.0, (1_000.0, 1_000.1));
+}
@@ -1,8 +1,11 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Map, Vec};
 
 use crate::{
-    contracts::{factory, pool},
-    utils::{desoroban_result, unwrap_call_result},
+    contracts::{
+        factory::{self, PoolParams, PoolRecord, TokenInfo},
+        pool,
+    },
+    utils::{desoroban_result, float_to_uint, float_to_uint_sp, unwrap_call_result},
 };
 
 pub struct PoolFactory {
@@ -34,6 +37,19 @@ impl PoolFactory {
         token_b: &Address,
         fee_share_bp: u128,
         admin_fee: u128,
+    ) -> Address {
+        self.create_pair_with_tier(admin, a, token_a, token_b, fee_share_bp, admin_fee, 0)
+    }
+
+    pub fn create_pair_with_tier(
+        &self,
+        admin: &Address,
+        a: u128,
+        token_a: &Address,
+        token_b: &Address,
+        fee_share_bp: u128,
+        admin_fee: u128,
+        tier: u32,
     ) -> Address {
         unwrap_call_result(
             &self.env,
@@ -45,10 +61,62 @@ impl PoolFactory {
                 token_b,
                 &fee_share_bp,
                 &admin_fee,
+                &tier,
             )),
         )
     }
 
+    pub fn create_default_pair(
+        &self,
+        deployer: &Address,
+        token_a: &Address,
+        token_b: &Address,
+    ) -> Address {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_create_default_pair(deployer, token_a, token_b),
+            ),
+        )
+    }
+
+    pub fn set_permissionless(&self, permissionless: bool) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_set_permissionless(&permissionless)),
+        );
+    }
+
+    pub fn set_default_pool_params(&self, a: u128, fee_share_bp: u128, admin_fee_share_bp: u128) {
+        let params = PoolParams {
+            a,
+            fee_share_bp,
+            admin_fee_share_bp,
+        };
+
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_set_default_pool_params(&params)),
+        );
+    }
+
+    pub fn set_pool_params_ranges(
+        &self,
+        a_range: (u128, u128),
+        fee_share_bp_range: (u128, u128),
+        admin_fee_share_bp_range: (u128, u128),
+    ) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_set_pool_params_ranges(
+                &a_range,
+                &fee_share_bp_range,
+                &admin_fee_share_bp_range,
+            )),
+        );
+    }
+
     pub fn pool(&self, token_a: &Address, token_b: &Address) -> Address {
         unwrap_call_result(
             &self.env,
@@ -56,3 +124,280 @@ impl PoolFactory {
         )
     }
 }
+
+impl PoolFactory {
+    pub fn pool_by_tier(&self, token_a: &Address, token_b: &Address, tier: u32) -> Address {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_pool_by_tier(token_a, token_b, &tier)),
+        )
+    }
+
+    pub fn pools_for_pair(&self, token_a: &Address, token_b: &Address) -> Vec<PoolRecord> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_pools_for_pair(token_a, token_b)),
+        )
+    }
+
+    pub fn pools_count(&self) -> u32 {
+        unwrap_call_result(&self.env, desoroban_result(self.client.try_pools_count()))
+    }
+
+    pub fn pools_page(&self, offset: u32, limit: u32) -> Vec<PoolRecord> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_pools_page(&offset, &limit)),
+        )
+    }
+
+    pub fn pools(&self) -> Map<Address, (Address, Address)> {
+        unwrap_call_result(&self.env, desoroban_result(self.client.try_pools()))
+    }
+
+    pub fn pool_by_index(&self, index: u32) -> PoolRecord {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_pool_by_index(&index)),
+        )
+    }
+
+    pub fn predict_pool_address(
+        &self,
+        deployer: &Address,
+        token_a: &Address,
+        token_b: &Address,
+        tier: u32,
+    ) -> Address {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_predict_pool_address_by_tier(deployer, token_a, token_b, &tier),
+            ),
+        )
+    }
+
+    pub fn set_pool_precision(&self, system_precision: u32, max_token_balance: u128) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_set_pool_precision(&system_precision, &max_token_balance),
+            ),
+        );
+    }
+
+    pub fn set_token_rate_provider(&self, token: &Address, rate_provider: Option<Address>) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_set_token_rate_provider(token, &rate_provider),
+            ),
+        );
+    }
+
+    pub fn set_rate_max_age(&self, rate_max_age: u64) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_set_rate_max_age(&rate_max_age)),
+        );
+    }
+
+    pub fn set_protocol_fee(&self, share_bp: u128, fee_to: &Address) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_set_protocol_fee(&share_bp, fee_to)),
+        );
+    }
+
+    pub fn sync_pools_protocol_fee(&self, pools: &Vec<Address>) -> Vec<(Address, bool)> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_sync_pools_protocol_fee(pools)),
+        )
+    }
+
+    pub fn collect_protocol_fees(
+        &self,
+        pools: &Vec<Address>,
+    ) -> Vec<(Address, Option<(u128, u128)>)> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_collect_protocol_fees(pools)),
+        )
+    }
+
+    pub fn set_pools_fee_share(
+        &self,
+        pools: &Vec<Address>,
+        fee_share_bp: u128,
+    ) -> Vec<(Address, bool)> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_set_pools_fee_share(pools, &fee_share_bp)),
+        )
+    }
+
+    pub fn set_pools_admin_fee_share(
+        &self,
+        pools: &Vec<Address>,
+        admin_fee_share_bp: u128,
+    ) -> Vec<(Address, bool)> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_set_pools_admin_fee_share(pools, &admin_fee_share_bp),
+            ),
+        )
+    }
+
+    pub fn ramp_pools_a(
+        &self,
+        pools: &Vec<Address>,
+        future_a: u128,
+        future_time: u64,
+    ) -> Vec<(Address, bool)> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_ramp_pools_a(pools, &future_a, &future_time)),
+        )
+    }
+
+    pub fn stop_pools_a_ramp(&self, pools: &Vec<Address>) -> Vec<(Address, bool)> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_stop_pools_a_ramp(pools)),
+        )
+    }
+
+    pub fn pause_pools(&self, pools: &Vec<Address>) -> Vec<(Address, bool)> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_pause_pools(pools)),
+        )
+    }
+
+    pub fn unpause_pools(&self, pools: &Vec<Address>) -> Vec<(Address, bool)> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_unpause_pools(pools)),
+        )
+    }
+
+    pub fn list_token(&self, token: &Address, decimals: u32, stable: bool) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_list_token(token, &decimals, &stable)),
+        );
+    }
+
+    pub fn block_token(&self, token: &Address) -> Vec<Address> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_block_token(token)),
+        )
+    }
+
+    pub fn token_info(&self, token: &Address) -> TokenInfo {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_token_info(token)),
+        )
+    }
+
+    pub fn remove_pair(
+        &self,
+        token_a: &Address,
+        token_b: &Address,
+        tier: u32,
+        withdraw_only: bool,
+    ) -> Address {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_remove_pair(token_a, token_b, &tier, &withdraw_only),
+            ),
+        )
+    }
+
+    pub fn replace_pair(
+        &self,
+        token_a: &Address,
+        token_b: &Address,
+        tier: u32,
+        pool: &Address,
+        withdraw_only: bool,
+    ) -> Address {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_replace_pair(
+                token_a,
+                token_b,
+                &tier,
+                pool,
+                &withdraw_only,
+            )),
+        )
+    }
+
+    /// (token_a, token_b) amounts
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_pair_with_liquidity(
+        &self,
+        deployer: &Address,
+        a: u128,
+        token_a: &Address,
+        token_b: &Address,
+        fee_share_bp: u128,
+        admin_fee: u128,
+        amounts: (f64, f64),
+        min_lp_amount: f64,
+    ) -> Address {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_create_pair_with_liquidity(
+                deployer,
+                deployer,
+                &a,
+                token_a,
+                token_b,
+                &fee_share_bp,
+                &admin_fee,
+                &0,
+                &(float_to_uint(amounts.0, 7), float_to_uint(amounts.1, 7)),
+                &float_to_uint_sp(min_lp_amount),
+            )),
+        )
+    }
+
+    /// Metapool of `token` against the default tier pool of the base pair
+    pub fn create_metapool(
+        &self,
+        admin: &Address,
+        a: u128,
+        token: &Address,
+        (base_token_a, base_token_b): (&Address, &Address),
+        fee_share_bp: u128,
+        admin_fee: u128,
+    ) -> Address {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_create_metapool(
+                admin,
+                admin,
+                &a,
+                token,
+                base_token_a,
+                base_token_b,
+                &0,
+                &fee_share_bp,
+                &admin_fee,
+                &0,
+            )),
+        )
+    }
+}
@@ -1,9 +1,15 @@
 mod factory;
+mod orders;
 mod pool;
+#[cfg(test)]
+mod rate_provider;
 mod token;
 mod user;
 
 pub use factory::*;
+pub use orders::*;
 pub use pool::*;
+#[cfg(test)]
+pub use rate_provider::*;
 pub use token::*;
 pub use user::*;
@@ -0,0 +1,114 @@
+use soroban_sdk::{Address, Env};
+
+use super::User;
+use crate::{
+    contracts::orders::{self, Direction, Token},
+    utils::{desoroban_result, float_to_uint, unwrap_call_result},
+};
+
+pub struct Orders {
+    pub id: soroban_sdk::Address,
+    pub client: orders::Client<'static>,
+    pub env: Env,
+}
+
+impl Orders {
+    /// Orders contract of `pool`
+    pub fn create(env: &Env, pool: &Address) -> Orders {
+        let id = env.register_contract_wasm(None, orders::WASM);
+        let client = orders::Client::new(env, &id);
+
+        client.initialize(pool);
+
+        Orders {
+            id,
+            client,
+            env: env.clone(),
+        }
+    }
+
+    pub fn swap(
+        &self,
+        sender: &User,
+        recipient: &User,
+        amount: f64,
+        receive_amount_min: f64,
+        direction: Direction,
+    ) -> u128 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_swap(
+                &sender.as_address(),
+                &recipient.as_address(),
+                &float_to_uint(amount, 7),
+                &float_to_uint(receive_amount_min, 7),
+                &direction,
+            )),
+        )
+    }
+
+    /// Price in basis points of the other token per sold one
+    pub fn place_order(&self, owner: &User, sell_token: Token, price_bp: u32, amount: f64) -> u64 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_place_order(
+                &owner.as_address(),
+                &sell_token,
+                &price_bp,
+                &float_to_uint(amount, 7),
+            )),
+        )
+    }
+
+    pub fn cancel_order(&self, owner: &User, id: u64) -> (u128, u128) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_cancel_order(&owner.as_address(), &id)),
+        )
+    }
+
+    pub fn claim_order(&self, owner: &User, id: u64) -> u128 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_claim_order(&owner.as_address(), &id)),
+        )
+    }
+
+    pub fn place_long_term_order(
+        &self,
+        owner: &User,
+        sell_token: Token,
+        amount: f64,
+        intervals: u32,
+    ) -> u64 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_place_long_term_order(
+                &owner.as_address(),
+                &sell_token,
+                &float_to_uint(amount, 7),
+                &intervals,
+            )),
+        )
+    }
+
+    pub fn withdraw_long_term_proceeds(&self, owner: &User, id: u64) -> u128 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_withdraw_long_term_proceeds(&owner.as_address(), &id),
+            ),
+        )
+    }
+
+    pub fn cancel_long_term_order(&self, owner: &User, id: u64) -> (u128, u128) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_cancel_long_term_order(&owner.as_address(), &id),
+            ),
+        )
+    }
+}
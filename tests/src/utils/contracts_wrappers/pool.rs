@@ -1,8 +1,8 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, BytesN, Env, Vec};
 
 use super::User;
 use crate::{
-    contracts::pool::{self, Direction, UserDeposit},
+    contracts::pool::{self, Direction, PoolOp, SwapIntent, SwapQuote, UserDeposit},
     utils::{
         desoroban_result, float_to_uint, float_to_uint_sp, percentage_to_bp, uint_to_float_sp,
         unwrap_call_result, CallResult,
@@ -35,15 +35,15 @@ impl Pool {
         )
     }
 
+    /// D of the balances drifts below the LP supply by the swap rounding only, up to 1 ppm
     pub fn assert_total_lp_less_or_equal_d(&self) {
-        let allowed_range = 0..2;
-        let total_lp_amount = self.total_lp() as i128;
-        let d = self.d() as i128;
-        let diff = total_lp_amount.abs_diff(d);
+        let total_lp_amount = self.total_lp();
+        let d = self.d();
+        let rounding = total_lp_amount / 1_000_000 + 2;
 
         assert!(
-            allowed_range.contains(&diff),
-            "InvariantFailed: Total lp amount  must be less or equal to D"
+            total_lp_amount <= d + rounding,
+            "InvariantFailed: Total lp amount {total_lp_amount} must be less or equal to D {d}"
         );
     }
 
@@ -63,6 +63,7 @@ impl Pool {
         assert_eq!(pool_info.token_balances.data, (0, 0));
         assert_eq!(pool_info.acc_rewards_per_share_p.data, (0, 0));
         assert_eq!(pool_info.admin_fee_amount.data, (0, 0));
+        assert_eq!(pool_info.protocol_fee_amount.data, (0, 0));
     }
 
     pub fn total_lp(&self) -> u128 {
@@ -106,6 +107,16 @@ impl Pool {
         );
     }
 
+    pub fn set_fee_share_by_factory(&self, fee_share: f64) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_set_fee_share_by_factory(&percentage_to_bp(fee_share)),
+            ),
+        );
+    }
+
     pub fn claim_rewards(&self, user: &User) {
         unwrap_call_result(
             &self.env,
@@ -113,6 +124,46 @@ impl Pool {
         );
     }
 
+    pub fn claim_rewards_for(&self, owner: &User, recipient: &User) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_claim_rewards_for(&owner.as_address(), &recipient.as_address()),
+            ),
+        );
+    }
+
+    pub fn set_operator(&self, user: &User, operator: Option<&User>) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_set_operator(&user.as_address(), &operator.map(User::as_address)),
+            ),
+        );
+    }
+
+    pub fn compound_rewards(&self, user: &User, min_lp_amount: f64) -> u128 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_compound_rewards(&user.as_address(), &float_to_uint_sp(min_lp_amount)),
+            ),
+        )
+    }
+
+    pub fn set_auto_compound(&self, user: &User, enabled: bool) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_set_auto_compound(&user.as_address(), &enabled),
+            ),
+        );
+    }
+
     pub fn claim_admin_fee(&self) {
         unwrap_call_result(
             &self.env,
@@ -120,6 +171,38 @@ impl Pool {
         );
     }
 
+    pub fn claim_admin_fee_to(
+        &self,
+        recipient: &User,
+        amounts: (u128, u128),
+        convert_to: Option<pool::Token>,
+        receive_amount_min: u128,
+    ) -> (u128, u128) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_claim_admin_fee_to(
+                &recipient.as_address(),
+                &amounts,
+                &convert_to,
+                &receive_amount_min,
+            )),
+        )
+    }
+
+    pub fn set_protocol_fee_share(&self, share_bp: u128) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_set_protocol_fee_share(&share_bp)),
+        );
+    }
+
+    pub fn claim_protocol_fee(&self, recipient: &User) -> (u128, u128) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_claim_protocol_fee(&recipient.as_address())),
+        )
+    }
+
     pub fn withdraw_checked(&self, user: &User, withdraw_amount: f64) -> CallResult {
         desoroban_result(
             self.client
@@ -131,13 +214,57 @@ impl Pool {
         unwrap_call_result(&self.env, self.withdraw_checked(user, withdraw_amount));
     }
 
+    /// (yusd, yaro) minimum amounts
+    pub fn withdraw_to(
+        &self,
+        sender: &User,
+        recipient: &User,
+        withdraw_amount: f64,
+        min_amounts: (f64, f64),
+    ) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_withdraw_to(
+                &sender.as_address(),
+                &recipient.as_address(),
+                &float_to_uint_sp(withdraw_amount),
+                &(
+                    float_to_uint(min_amounts.0, 7),
+                    float_to_uint(min_amounts.1, 7),
+                ),
+            )),
+        );
+    }
+
+    /// (yusd, yaro)
+    pub fn deposit_for(
+        &self,
+        sender: &User,
+        beneficiary: &User,
+        deposit_amounts: (f64, f64),
+        min_lp_amount: f64,
+    ) -> u128 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_deposit_for(
+                &sender.as_address(),
+                &beneficiary.as_address(),
+                &(
+                    float_to_uint(deposit_amounts.0, 7),
+                    float_to_uint(deposit_amounts.1, 7),
+                ),
+                &float_to_uint_sp(min_lp_amount),
+            )),
+        )
+    }
+
     /// (yusd, yaro)
     pub fn deposit_with_address_checked(
         &self,
         user: &Address,
         deposit_amounts: (f64, f64),
         min_lp_amount: f64,
-    ) -> CallResult {
+    ) -> CallResult<u128> {
         desoroban_result(self.client.try_deposit(
             user,
             &(
@@ -154,11 +281,11 @@ impl Pool {
         user: &Address,
         deposit_amounts: (f64, f64),
         min_lp_amount: f64,
-    ) {
+    ) -> u128 {
         unwrap_call_result(
             &self.env,
             self.deposit_with_address_checked(user, deposit_amounts, min_lp_amount),
-        );
+        )
     }
 
     /// (yusd, yaro)
@@ -167,13 +294,13 @@ impl Pool {
         user: &User,
         deposit_amounts: (f64, f64),
         min_lp_amount: f64,
-    ) -> CallResult {
+    ) -> CallResult<u128> {
         self.deposit_with_address_checked(&user.as_address(), deposit_amounts, min_lp_amount)
     }
 
     /// (yusd, yaro)
-    pub fn deposit(&self, user: &User, deposit_amounts: (f64, f64), min_lp_amount: f64) {
-        self.deposit_with_address(&user.as_address(), deposit_amounts, min_lp_amount);
+    pub fn deposit(&self, user: &User, deposit_amounts: (f64, f64), min_lp_amount: f64) -> u128 {
+        self.deposit_with_address(&user.as_address(), deposit_amounts, min_lp_amount)
     }
 
     pub fn swap_checked(
@@ -206,4 +333,158 @@ impl Pool {
             self.swap_checked(sender, recipient, amount, receive_amount_min, direction),
         );
     }
+
+    pub fn transfer_lp(&self, from: &User, to: &User, lp_amount: f64) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_transfer_lp(
+                &from.as_address(),
+                &to.as_address(),
+                &float_to_uint_sp(lp_amount),
+            )),
+        );
+    }
+
+    pub fn lock(&self, sender: &User, lp_amount: f64, months: u32) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_lock(
+                &sender.as_address(),
+                &float_to_uint_sp(lp_amount),
+                &months,
+            )),
+        );
+    }
+
+    pub fn unlock(&self, user: &User) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_unlock(&user.as_address())),
+        );
+    }
+
+    pub fn multicall_checked(&self, sender: &User, ops: Vec<PoolOp>) -> CallResult<Vec<u128>> {
+        desoroban_result(self.client.try_multicall(&sender.as_address(), &ops))
+    }
+
+    pub fn multicall(&self, sender: &User, ops: Vec<PoolOp>) -> Vec<u128> {
+        unwrap_call_result(&self.env, self.multicall_checked(sender, ops))
+    }
+
+    pub fn set_intent_signer(&self, owner: &User, public_key: &BytesN<32>) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_set_intent_signer(&owner.as_address(), public_key),
+            ),
+        );
+    }
+
+    pub fn execute_signed_swap(
+        &self,
+        intent: &SwapIntent,
+        signature: &BytesN<64>,
+        amount_in: f64,
+    ) -> u128 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_execute_signed_swap(
+                intent,
+                signature,
+                &float_to_uint(amount_in, 7),
+            )),
+        )
+    }
+
+    pub fn cancel_intent(&self, owner: &User, nonce: u64) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_cancel_intent(&owner.as_address(), &nonce)),
+        );
+    }
+
+    pub fn quote_curve_swap(&self, amount: f64, direction: Direction) -> SwapQuote {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_quote_curve_swap(&float_to_uint(amount, 7), &direction),
+            ),
+        )
+    }
+
+    pub fn quote_curve_swap_exact_out(&self, amount: f64, direction: Direction) -> SwapQuote {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_quote_curve_swap_exact_out(&float_to_uint(amount, 7), &direction),
+            ),
+        )
+    }
+
+    pub fn swap_underlying(
+        &self,
+        sender: &User,
+        recipient: &User,
+        amount: f64,
+        receive_amount_min: f64,
+        token_from: &Address,
+        token_to: &Address,
+    ) -> u128 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_swap_underlying(
+                &sender.as_address(),
+                &recipient.as_address(),
+                &float_to_uint(amount, 7),
+                &float_to_uint(receive_amount_min, 7),
+                token_from,
+                token_to,
+            )),
+        )
+    }
+
+    pub fn claim_base_rewards(&self) -> (u128, u128) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_claim_base_rewards()),
+        )
+    }
+
+    pub fn add_reward_token(&self, token: &Address) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_add_reward_token(token)),
+        );
+    }
+
+    pub fn notify_reward_amount(&self, token: &Address, amount: f64, duration: u64) {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_notify_reward_amount(
+                token,
+                &float_to_uint(amount, 7),
+                &duration,
+            )),
+        );
+    }
+
+    pub fn claim_all(&self, user: &User) -> Vec<(Address, u128)> {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(self.client.try_claim_all(&user.as_address())),
+        )
+    }
+
+    pub fn pending_reward_token(&self, user: &User, token: &Address) -> u128 {
+        unwrap_call_result(
+            &self.env,
+            desoroban_result(
+                self.client
+                    .try_pending_reward_token(&user.as_address(), token),
+            ),
+        )
+    }
 }
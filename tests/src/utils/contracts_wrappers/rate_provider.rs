@@ -0,0 +1,41 @@
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+use crate::utils::float_to_uint;
+
+/// Stands in for the rate provider of a yield-bearing token
+#[contract]
+pub struct MockRateProvider;
+
+#[contractimpl]
+impl MockRateProvider {
+    pub fn set_rate(env: Env, rate: u128) {
+        env.storage().instance().set(&symbol_short!("rate"), &rate);
+    }
+
+    pub fn get_rate(env: Env) -> u128 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("rate"))
+            .unwrap_or(1_000_000_000_000_000_000)
+    }
+}
+
+pub struct RateProvider {
+    pub id: Address,
+    pub client: MockRateProviderClient<'static>,
+}
+
+impl RateProvider {
+    pub fn create(env: &Env, rate: f64) -> RateProvider {
+        let id = env.register_contract(None, MockRateProvider);
+        let client = MockRateProviderClient::new(env, &id);
+        let rate_provider = RateProvider { id, client };
+        rate_provider.set_rate(rate);
+
+        rate_provider
+    }
+
+    pub fn set_rate(&self, rate: f64) {
+        self.client.set_rate(&float_to_uint(rate, 18));
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{testutils::Ledger, Address, Env};
 
 use crate::{
     contracts::pool::{Deposit, Direction, RewardsClaimed, Swapped, Withdraw},
@@ -92,6 +92,8 @@ impl TestingEnv {
         native_token.default_airdrop(&bob);
 
         let (yusd_token, yaro_token) = TestingEnv::generate_token_pair(&env, admin.as_ref());
+        factory.list_token(&yusd_token.id, 7, true);
+        factory.list_token(&yaro_token.id, 7, true);
         let pool = TestingEnv::create_pool(
             &env,
             &factory,
@@ -133,6 +135,12 @@ impl TestingEnv {
         self
     }
 
+    pub fn skip_time(&self, seconds: u64) {
+        self.env
+            .ledger()
+            .with_mut(|ledger| ledger.timestamp += seconds);
+    }
+
     pub fn generate_token_pair(env: &Env, admin: &Address) -> (Token, Token) {
         let token_a = Token::create(env, admin);
         let token_b = Token::create(env, admin);
@@ -140,6 +148,16 @@ impl TestingEnv {
         (token_a, token_b)
     }
 
+    /// Token pair listed in the factory
+    pub fn generate_listed_token_pair(&self) -> (Token, Token) {
+        let (token_a, token_b) = TestingEnv::generate_token_pair(&self.env, self.admin.as_ref());
+
+        self.factory.list_token(&token_a.id, 7, false);
+        self.factory.list_token(&token_b.id, 7, false);
+
+        (token_a, token_b)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn create_pool(
         env: &Env,
@@ -186,6 +204,7 @@ impl TestingEnv {
             get_latest_event::<RewardsClaimed>(&self.env).expect("Expected RewardsClaimed");
 
         assert_eq!(rewards_claimed.user, expected_user.as_address());
+        assert_eq!(rewards_claimed.recipient, expected_user.as_address());
         assert_rel_eq(
             rewards_claimed.rewards.0,
             float_to_uint(expected_yusd_reward, 7),
@@ -235,6 +254,7 @@ impl TestingEnv {
         let withdraw = get_latest_event::<Withdraw>(&self.env).expect("Expected Withdraw");
 
         assert_eq!(withdraw.user, expected_user.as_address());
+        assert_eq!(withdraw.recipient, expected_user.as_address());
         assert_eq!(withdraw.lp_amount, float_to_uint_sp(lp_amount));
 
         assert_rel_eq(withdraw.amounts.0, float_to_uint_sp(yusd_amount), 2);
@@ -253,6 +273,7 @@ impl TestingEnv {
         let deposit = get_latest_event::<Deposit>(&self.env).expect("Expected Deposit");
 
         assert_eq!(deposit.user, expected_user.as_address());
+        assert_eq!(deposit.beneficiary, expected_user.as_address());
         assert_eq!(deposit.amounts.0, float_to_uint(yusd_deposit, 7));
         assert_eq!(deposit.amounts.1, float_to_uint(yaro_deposit, 7));
         assert_rel_eq(float_to_uint_sp(expected_lp_amount), deposit.lp_amount, 10);